@@ -0,0 +1,251 @@
+use crate::{
+    traversal::depth_first::nodes_with_subtree_sizes, tree::Tree, tree_node::TreeNode,
+    variants::tree_variant::TreeVariant,
+};
+use orx_selfref_col::Node;
+use std::{cmp::Reverse, collections::HashMap, ops::Range};
+
+/// Heavy-light decomposition of a tree; created by [`Tree::heavy_light_decomposition`].
+///
+/// Nodes are assigned positions `0..len` in depth first order where the heavy child, the child with the largest
+/// subtree, is always visited first. Therefore, each heavy chain occupies a contiguous range of positions, starting
+/// at its head, and the path between any two nodes is covered by O(log n) such ranges; see [`Self::path_segments`].
+/// These ranges can be used with a user-provided segment structure built over the positions.
+pub struct HeavyLightDecomposition<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    nodes: Vec<TreeNode<'b, 'a, V, T>>,
+    parents: Vec<Option<usize>>,
+    depths: Vec<usize>,
+    heads: Vec<usize>,
+    chain_ids: Vec<usize>,
+    positions: HashMap<*const Node<'a, V, T>, usize>,
+}
+
+impl<'b, 'a, V, T> HeavyLightDecomposition<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    fn new(root: Option<TreeNode<'b, 'a, V, T>>) -> Self {
+        let mut hld = Self {
+            nodes: vec![],
+            parents: vec![],
+            depths: vec![],
+            heads: vec![],
+            chain_ids: vec![],
+            positions: HashMap::new(),
+        };
+        let Some(root) = root else {
+            return hld;
+        };
+
+        let (nodes, sizes) = nodes_with_subtree_sizes(root);
+        let mut num_chains = 0;
+        // (position in depth first order, parent position, head position if in the parent's chain, depth)
+        let mut stack = vec![(0, None, None, 0)];
+        while let Some((dfs_position, parent, head, depth)) = stack.pop() {
+            let position = hld.nodes.len();
+            let head = match head {
+                Some(head) => head,
+                None => {
+                    num_chains += 1;
+                    position
+                }
+            };
+            hld.nodes.push(nodes[dfs_position]);
+            hld.parents.push(parent);
+            hld.depths.push(depth);
+            hld.heads.push(head);
+            hld.chain_ids.push(num_chains - 1);
+            hld.positions.insert(nodes[dfs_position].node, position);
+
+            let end = dfs_position + sizes[dfs_position];
+            let mut children = vec![];
+            let mut child = dfs_position + 1;
+            while child < end {
+                children.push(child);
+                child += sizes[child];
+            }
+            // first of the largest children in case of ties
+            let heavy = children
+                .iter()
+                .enumerate()
+                .max_by_key(|(i, c)| (sizes[**c], Reverse(*i)));
+            if let Some((heavy, _)) = heavy {
+                let heavy = children.remove(heavy);
+                let light = children.into_iter().rev();
+                stack.extend(light.map(|c| (c, Some(position), None, depth + 1)));
+                stack.push((heavy, Some(position), Some(head), depth + 1));
+            }
+        }
+        hld
+    }
+
+    /// Number of nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns whether or not the decomposed tree is empty.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the position of the `node`; None if the node does not belong to the decomposed tree.
+    pub fn position_of(&self, node: &TreeNode<'b, 'a, V, T>) -> Option<usize> {
+        self.positions.get(&(node.node as *const _)).copied()
+    }
+
+    /// Returns the node at the given `position`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position` is out of bounds.
+    pub fn node(&self, position: usize) -> TreeNode<'b, 'a, V, T> {
+        self.nodes[position]
+    }
+
+    /// Returns the position of the head, the top-most node, of the heavy chain that the node at `position` belongs to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position` is out of bounds.
+    pub fn chain_head(&self, position: usize) -> usize {
+        self.heads[position]
+    }
+
+    /// Returns the id of the heavy chain that the node at `position` belongs to; chains are numbered `0, 1, ...`
+    /// in the order of the positions of their heads.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position` is out of bounds.
+    pub fn chain_id(&self, position: usize) -> usize {
+        self.chain_ids[position]
+    }
+
+    /// Returns ranges of positions which together cover exactly the nodes on the path between the nodes at
+    /// positions `a` and `b`, both inclusive. There are O(log n) ranges.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is out of bounds.
+    pub fn path_segments(&self, a: usize, b: usize) -> Vec<Range<usize>> {
+        let (mut a, mut b) = (a, b);
+        let mut segments = vec![];
+        while self.heads[a] != self.heads[b] {
+            if self.depths[self.heads[a]] < self.depths[self.heads[b]] {
+                std::mem::swap(&mut a, &mut b);
+            }
+            segments.push(self.heads[a]..(a + 1));
+            a = self.parents[self.heads[a]].expect("head of a non-root chain has a parent");
+        }
+        segments.push(a.min(b)..(a.max(b) + 1));
+        segments
+    }
+}
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Computes the heavy-light decomposition of the subtree rooted at this node; see [`HeavyLightDecomposition`].
+    pub fn heavy_light_decomposition(&self) -> HeavyLightDecomposition<'b, 'a, V, T> {
+        HeavyLightDecomposition::new(Some(*self))
+    }
+}
+
+impl<'a, V, T> Tree<'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Computes the heavy-light decomposition of the tree; see [`HeavyLightDecomposition`].
+    pub fn heavy_light_decomposition(&self) -> HeavyLightDecomposition<'_, 'a, V, T> {
+        HeavyLightDecomposition::new(self.root())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn heavy_light_decomposition_empty() {
+        let tree = Tree::<AnyAry, char>::new();
+        let hld = tree.heavy_light_decomposition();
+        assert!(hld.is_empty());
+        assert_eq!(hld.len(), 0);
+    }
+
+    #[test]
+    fn heavy_light_decomposition_root_only() {
+        let tree = Tree::<AnyAry, _>::with_root('a');
+        let root = tree.root().unwrap();
+        let hld = tree.heavy_light_decomposition();
+        assert_eq!(hld.len(), 1);
+        assert_eq!(hld.position_of(&root), Some(0));
+        assert_eq!(hld.node(0).value(), &'a');
+        assert_eq!(hld.chain_head(0), 0);
+        assert_eq!(hld.chain_id(0), 0);
+        let segments = hld.path_segments(0, 0);
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0], 0..1);
+
+        let other = Tree::<AnyAry, _>::with_root('a');
+        assert_eq!(hld.position_of(&other.root().unwrap()), None);
+    }
+
+    #[test]
+    fn heavy_light_decomposition_multi_level() {
+        // a
+        // ├──b
+        // │  ├──d
+        // │  └──e
+        // └──c
+        //    └──f
+        //       ├──g
+        //       └──h
+        let mut tree = sample();
+        tree.push_child_at(&[1, 0], 'h');
+        let hld = tree.heavy_light_decomposition();
+        assert_eq!(hld.len(), 8);
+
+        let values: String = (0..hld.len()).map(|i| *hld.node(i).value()).collect();
+        assert_eq!(values, "acfghbde");
+        let heads: String = (0..hld.len())
+            .map(|i| *hld.node(hld.chain_head(i)).value())
+            .collect();
+        assert_eq!(heads, "aaaahbbe");
+        let chain_ids: Vec<_> = (0..hld.len()).map(|i| hld.chain_id(i)).collect();
+        assert_eq!(chain_ids, [0, 0, 0, 0, 1, 2, 2, 3]);
+
+        let e = tree.node_at(&[0, 1]).unwrap();
+        assert_eq!(hld.position_of(&e), Some(7));
+
+        // h -> f -> c -> a -> b -> e
+        assert_eq!(hld.path_segments(4, 7), [4..5, 7..8, 5..6, 0..3]);
+        // g -> f -> c within the chain of a
+        assert_eq!(hld.path_segments(3, 1), vec![1..4]);
+        // d -> b
+        assert_eq!(hld.path_segments(6, 5), vec![5..7]);
+    }
+
+    #[test]
+    fn heavy_light_decomposition_of_subtree() {
+        let tree = sample();
+        let b = tree.node_at(&[0]).unwrap();
+        let hld = b.heavy_light_decomposition();
+
+        let values: String = (0..hld.len()).map(|i| *hld.node(i).value()).collect();
+        assert_eq!(values, "bde");
+        let heads: Vec<_> = (0..hld.len()).map(|i| hld.chain_head(i)).collect();
+        assert_eq!(heads, [0, 0, 2]);
+        assert_eq!(hld.path_segments(1, 2), [2..3, 0..2]);
+        assert_eq!(hld.position_of(&tree.root().unwrap()), None);
+    }
+}
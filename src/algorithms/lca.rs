@@ -0,0 +1,175 @@
+use crate::{
+    traversal::depth_first::nodes_with_subtree_sizes, tree::Tree, tree_node::TreeNode,
+    variants::tree_variant::TreeVariant,
+};
+use orx_selfref_col::Node;
+use std::collections::HashMap;
+
+/// Index answering lowest common ancestor queries in constant time; built by [`LcaIndex::build`] in O(n log n).
+///
+/// The index is a snapshot of the tree at the time it is built and must be re-built after the structure of the tree
+/// changes.
+pub struct LcaIndex<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    nodes: Vec<TreeNode<'b, 'a, V, T>>,
+    first_in_tour: Vec<usize>,
+    sparse_table: Vec<Vec<usize>>,
+    positions: HashMap<*const Node<'a, V, T>, usize>,
+}
+
+impl<'b, 'a, V, T> LcaIndex<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Builds the index over the current nodes of the `tree`.
+    pub fn build(tree: &'b Tree<'a, V, T>) -> Self {
+        Self::new(tree.root())
+    }
+
+    fn new(root: Option<TreeNode<'b, 'a, V, T>>) -> Self {
+        let (nodes, sizes) = match root {
+            Some(root) => nodes_with_subtree_sizes(root),
+            None => (vec![], vec![]),
+        };
+
+        // euler tour over positions in depth first order; since an ancestor precedes all of its descendants,
+        // the lowest common ancestor is the minimum position between the first visits of two nodes
+        let mut tour = Vec::with_capacity((2 * nodes.len()).saturating_sub(1));
+        let mut first_in_tour = Vec::with_capacity(nodes.len());
+        let mut ancestors: Vec<usize> = vec![];
+        for position in 0..=nodes.len() {
+            while let Some(&top) = ancestors.last() {
+                if position < top + sizes[top] {
+                    break;
+                }
+                ancestors.pop();
+                if let Some(&parent) = ancestors.last() {
+                    tour.push(parent);
+                }
+            }
+            if position < nodes.len() {
+                first_in_tour.push(tour.len());
+                tour.push(position);
+                ancestors.push(position);
+            }
+        }
+
+        let mut sparse_table = vec![tour];
+        let mut width = 1;
+        while 2 * width <= sparse_table[0].len() {
+            let prev = sparse_table.last().expect("at least one level exists");
+            let level = (0..(prev.len() - width))
+                .map(|i| prev[i].min(prev[i + width]))
+                .collect();
+            sparse_table.push(level);
+            width *= 2;
+        }
+
+        let positions = nodes
+            .iter()
+            .enumerate()
+            .map(|(position, node)| (node.node as *const _, position))
+            .collect();
+
+        Self {
+            nodes,
+            first_in_tour,
+            sparse_table,
+            positions,
+        }
+    }
+
+    /// Number of indexed nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns whether or not the index is empty.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the lowest common ancestor of nodes `a` and `b`, where a node is an ancestor of itself;
+    /// None if either of the nodes does not belong to the indexed tree.
+    pub fn lca(
+        &self,
+        a: &TreeNode<'b, 'a, V, T>,
+        b: &TreeNode<'b, 'a, V, T>,
+    ) -> Option<TreeNode<'b, 'a, V, T>> {
+        let a = self.first_in_tour[*self.positions.get(&(a.node as *const _))?];
+        let b = self.first_in_tour[*self.positions.get(&(b.node as *const _))?];
+        let (begin, end) = (a.min(b), a.max(b) + 1);
+        let level = (end - begin).ilog2() as usize;
+        let row = &self.sparse_table[level];
+        let position = row[begin].min(row[end - (1 << level)]);
+        Some(self.nodes[position])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LcaIndex;
+    use crate::{
+        test_builder::{chain, sample},
+        tree::Tree,
+        variants::any_ary::AnyAry,
+    };
+
+    #[test]
+    fn lca_index_empty() {
+        let tree = Tree::<AnyAry, char>::new();
+        let index = LcaIndex::build(&tree);
+        assert!(index.is_empty());
+        assert_eq!(index.len(), 0);
+    }
+
+    #[test]
+    fn lca_index_root_only() {
+        let tree = Tree::<AnyAry, _>::with_root('a');
+        let root = tree.root().unwrap();
+        let index = LcaIndex::build(&tree);
+        assert_eq!(index.len(), 1);
+        assert_eq!(index.lca(&root, &root).map(|x| *x.value()), Some('a'));
+
+        let other = Tree::<AnyAry, _>::with_root('a');
+        assert!(index.lca(&root, &other.root().unwrap()).is_none());
+    }
+
+    #[test]
+    fn lca_index_multi_level() {
+        let tree = sample();
+        let index = LcaIndex::build(&tree);
+        assert_eq!(index.len(), 7);
+
+        let node = |path: &[u32]| tree.node_at(path).unwrap();
+        let lca = |a: &[u32], b: &[u32]| *index.lca(&node(a), &node(b)).unwrap().value();
+        // siblings
+        assert_eq!(lca(&[0, 0], &[0, 1]), 'b');
+        // different branches of the root at different depths
+        assert_eq!(lca(&[0, 0], &[1, 0, 0]), 'a');
+        assert_eq!(lca(&[0, 1], &[1]), 'a');
+        // ancestor and its descendant, in both argument orders
+        assert_eq!(lca(&[1, 0, 0], &[1]), 'c');
+        assert_eq!(lca(&[1], &[1, 0, 0]), 'c');
+        assert_eq!(lca(&[1, 0, 0], &[1, 0]), 'f');
+        assert_eq!(lca(&[], &[1, 0, 0]), 'a');
+        // node with itself
+        assert_eq!(lca(&[0], &[0]), 'b');
+    }
+
+    #[test]
+    fn lca_index_deep() {
+        let tree = chain(1000);
+        let index = LcaIndex::build(&tree);
+        let node = |depth| tree.node_at(&vec![0; depth]).unwrap();
+        assert_eq!(
+            index.lca(&node(999), &node(500)).map(|x| *x.value()),
+            Some(500)
+        );
+        assert_eq!(index.lca(&node(1), &node(998)).map(|x| *x.value()), Some(1));
+    }
+}
@@ -0,0 +1,2 @@
+pub mod heavy_light;
+pub mod lca;
@@ -0,0 +1,149 @@
+use crate::{
+    tree::Tree,
+    variants::{
+        any_ary::AnyAry,
+        dary::{Binary, Ternary},
+    },
+};
+
+/// Tree of any of the common variants, allowing trees of different variants but the same value type to be stored
+/// together without the variant generic parameter.
+pub enum AnyTree<'a, T>
+where
+    T: 'a,
+{
+    /// Tree where each node can have any number of children.
+    AnyAry(Tree<'a, AnyAry, T>),
+    /// Tree where each node can have at most two children.
+    Binary(Tree<'a, Binary, T>),
+    /// Tree where each node can have at most three children.
+    Ternary(Tree<'a, Ternary, T>),
+}
+
+impl<'a, T> AnyTree<'a, T>
+where
+    T: 'a,
+{
+    // get
+    /// Number of nodes of the tree.
+    pub fn num_nodes(&self) -> usize {
+        match self {
+            Self::AnyAry(tree) => tree.num_nodes(),
+            Self::Binary(tree) => tree.num_nodes(),
+            Self::Ternary(tree) => tree.num_nodes(),
+        }
+    }
+
+    /// Returns whether or not the tree is empty.
+    pub fn is_empty(&self) -> bool {
+        self.num_nodes() == 0
+    }
+
+    /// Returns the value of the root; None if the tree is empty.
+    pub fn root_value(&self) -> Option<&T> {
+        match self {
+            Self::AnyAry(tree) => tree.root().map(|root| root.value()),
+            Self::Binary(tree) => tree.root().map(|root| root.value()),
+            Self::Ternary(tree) => tree.root().map(|root| root.value()),
+        }
+    }
+
+    /// Returns the structural version of the tree; see [`Tree::structural_version`].
+    pub fn structural_version(&self) -> u64 {
+        match self {
+            Self::AnyAry(tree) => tree.structural_version(),
+            Self::Binary(tree) => tree.structural_version(),
+            Self::Ternary(tree) => tree.structural_version(),
+        }
+    }
+
+    // iter
+    /// Returns an iterator over values of all nodes of the tree in depth first order; see [`Tree::iter_dfs`].
+    pub fn iter_dfs(&self) -> Box<dyn Iterator<Item = &T> + '_> {
+        match self {
+            Self::AnyAry(tree) => Box::new(tree.iter_dfs()),
+            Self::Binary(tree) => Box::new(tree.iter_dfs()),
+            Self::Ternary(tree) => Box::new(tree.iter_dfs()),
+        }
+    }
+
+    /// Returns an iterator over values of all nodes of the tree in breadth first order; see [`Tree::iter_bfs`].
+    pub fn iter_bfs(&self) -> Box<dyn Iterator<Item = &T> + '_> {
+        match self {
+            Self::AnyAry(tree) => Box::new(tree.iter_bfs()),
+            Self::Binary(tree) => Box::new(tree.iter_bfs()),
+            Self::Ternary(tree) => Box::new(tree.iter_bfs()),
+        }
+    }
+
+    // mut
+    /// Replaces the value of the root and returns the prior value; see [`Tree::replace_root`].
+    pub fn replace_root(&mut self, value: T) -> Option<T> {
+        match self {
+            Self::AnyAry(tree) => tree.replace_root(value),
+            Self::Binary(tree) => tree.replace_root(value),
+            Self::Ternary(tree) => tree.replace_root(value),
+        }
+    }
+
+    /// Removes all nodes from the tree; see [`Tree::clear_iterative`].
+    pub fn clear(&mut self) {
+        match self {
+            Self::AnyAry(tree) => tree.clear_iterative(),
+            Self::Binary(tree) => tree.clear_iterative(),
+            Self::Ternary(tree) => tree.clear_iterative(),
+        }
+    }
+}
+
+impl<'a, T> From<Tree<'a, AnyAry, T>> for AnyTree<'a, T> {
+    fn from(tree: Tree<'a, AnyAry, T>) -> Self {
+        Self::AnyAry(tree)
+    }
+}
+
+impl<'a, T> From<Tree<'a, Binary, T>> for AnyTree<'a, T> {
+    fn from(tree: Tree<'a, Binary, T>) -> Self {
+        Self::Binary(tree)
+    }
+}
+
+impl<'a, T> From<Tree<'a, Ternary, T>> for AnyTree<'a, T> {
+    fn from(tree: Tree<'a, Ternary, T>) -> Self {
+        Self::Ternary(tree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AnyTree;
+    use crate::{
+        tree::Tree,
+        variants::{
+            any_ary::AnyAry,
+            dary::{Binary, Ternary},
+        },
+    };
+
+    #[test]
+    fn any_tree_of_different_variants() {
+        let mut trees: Vec<AnyTree<_>> = vec![
+            Tree::<AnyAry, _>::with_root('a').into(),
+            Tree::<Binary, _>::with_root('b').into(),
+            Tree::<Ternary, _>::new().into(),
+        ];
+
+        let roots: Vec<_> = trees.iter().map(|x| x.root_value().copied()).collect();
+        assert_eq!(roots, [Some('a'), Some('b'), None]);
+        assert_eq!(trees[0].iter_dfs().collect::<Vec<_>>(), [&'a']);
+        assert_eq!(trees[1].iter_bfs().collect::<Vec<_>>(), [&'b']);
+
+        assert_eq!(trees[2].replace_root('c'), None);
+        assert_eq!(trees[2].num_nodes(), 1);
+        assert_eq!(trees[2].structural_version(), 1);
+
+        trees[0].clear();
+        assert!(trees[0].is_empty());
+        assert_eq!(trees[0].iter_dfs().count(), 0);
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{tree_node::TreeNode, variants::tree_variant::TreeVariant};
+
+impl<'b, 'a, V, T> Clone for TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'b, 'a, V, T> Copy for TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+}
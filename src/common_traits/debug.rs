@@ -1,15 +1,16 @@
+use super::display::fmt_subtree;
 use crate::{tree::Tree, tree_node::TreeNode, variants::tree_variant::TreeVariant};
 use std::fmt::Debug;
 
-impl<'a, V, T> Debug for TreeNode<'a, V, T>
+impl<'b, 'a, V, T> Debug for TreeNode<'b, 'a, V, T>
 where
     T: 'a + Debug,
     V: TreeVariant<'a, T>,
 {
+    /// Renders the subtree rooted at this node in the same layout as [`Display`](std::fmt::Display),
+    /// writing the values with their debug representations.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("TreeNode")
-            .field("node", &self.node.data().expect("is-some"))
-            .finish()
+        fmt_subtree(self, f, |f, value| write!(f, "{:?}", value))
     }
 }
 
@@ -18,10 +19,36 @@ where
     T: 'a + Debug,
     V: TreeVariant<'a, T>,
 {
+    /// Renders the subtree rooted at the root as in [`TreeNode`]'s Debug;
+    /// an empty tree is written as `Tree { num_nodes: 0 }` so that it is never rendered as an empty string.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Tree")
-            .field("num_nodes", &self.num_nodes())
-            .field("root", &self.root())
-            .finish()
+        match self.root() {
+            Some(root) => root.fmt(f),
+            None => f
+                .debug_struct("Tree")
+                .field("num_nodes", &self.num_nodes())
+                .finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn debug_subtree() {
+        let tree = sample();
+        let expected = "'a'\n├──'b'\n│  ├──'d'\n│  └──'e'\n└──'c'\n   └──'f'\n      └──'g'\n";
+        assert_eq!(format!("{:?}", tree), expected);
+
+        let c = tree.node_at(&[1]).unwrap();
+        assert_eq!(format!("{:?}", c), "'c'\n└──'f'\n   └──'g'\n");
+    }
+
+    #[test]
+    fn debug_empty_tree() {
+        let tree = Tree::<AnyAry, char>::new();
+        assert_eq!(format!("{:?}", tree), "Tree { num_nodes: 0 }");
     }
 }
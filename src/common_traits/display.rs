@@ -0,0 +1,113 @@
+use crate::{tree::Tree, tree_node::TreeNode, variants::tree_variant::TreeVariant};
+use std::fmt::{Display, Formatter};
+
+impl<'b, 'a, V, T> Display for TreeNode<'b, 'a, V, T>
+where
+    T: 'a + Display,
+    V: TreeVariant<'a, T>,
+{
+    /// Renders the subtree rooted at this node, one node per line:
+    ///
+    /// ```text
+    /// a
+    /// ├──b
+    /// │  ├──d
+    /// │  └──e
+    /// └──c
+    ///    └──f
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_subtree(self, f, |f, value| write!(f, "{}", value))
+    }
+}
+
+/// Writes the subtree rooted at the `node` one node per line, prefixed by the guides to its ancestors,
+/// using `write_value` to write the value of each node.
+pub(super) fn fmt_subtree<'a, V, T, W>(
+    node: &TreeNode<'_, 'a, V, T>,
+    f: &mut Formatter<'_>,
+    mut write_value: W,
+) -> std::fmt::Result
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+    W: FnMut(&mut Formatter<'_>, &T) -> std::fmt::Result,
+{
+    let mut result = Ok(());
+    node.visit_with_guides(|_, is_last_sibling_chain, value| {
+        if result.is_ok() {
+            result = fmt_line(f, is_last_sibling_chain, value, &mut write_value);
+        }
+    });
+    result
+}
+
+fn fmt_line<T, W>(
+    f: &mut Formatter<'_>,
+    is_last_sibling_chain: &[bool],
+    value: &T,
+    write_value: &mut W,
+) -> std::fmt::Result
+where
+    W: FnMut(&mut Formatter<'_>, &T) -> std::fmt::Result,
+{
+    if let Some((is_last, ancestors)) = is_last_sibling_chain.split_last() {
+        for ancestor_is_last in ancestors {
+            match ancestor_is_last {
+                true => write!(f, "   ")?,
+                false => write!(f, "│  ")?,
+            }
+        }
+        match is_last {
+            true => write!(f, "└──")?,
+            false => write!(f, "├──")?,
+        }
+    }
+    write_value(f, value)?;
+    writeln!(f)
+}
+
+impl<'a, V, T> Display for Tree<'a, V, T>
+where
+    T: 'a + Display,
+    V: TreeVariant<'a, T>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.root() {
+            Some(root) => root.fmt(f),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn display_empty() {
+        let tree = Tree::<AnyAry, char>::new();
+        assert_eq!(tree.to_string(), "");
+    }
+
+    #[test]
+    fn display_root_only() {
+        let tree = Tree::<AnyAry, _>::with_root('a');
+        assert_eq!(tree.to_string(), "a\n");
+        assert_eq!(tree.root().unwrap().to_string(), "a\n");
+    }
+
+    #[test]
+    fn display_subtree() {
+        let tree = sample();
+        assert_eq!(
+            tree.to_string(),
+            "a\n├──b\n│  ├──d\n│  └──e\n└──c\n   └──f\n      └──g\n"
+        );
+
+        let b = tree.node_at(&[0]).unwrap();
+        assert_eq!(b.to_string(), "b\n├──d\n└──e\n");
+        let f = tree.node_at(&[1, 0]).unwrap();
+        assert_eq!(f.to_string(), "f\n└──g\n");
+    }
+}
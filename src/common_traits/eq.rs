@@ -0,0 +1,81 @@
+use crate::{tree::Tree, variants::tree_variant::TreeVariant};
+
+/// Two trees are equal if they have the same shape and equal values at the same positions.
+///
+/// The comparison is iterative and safe regardless of the depth of the trees.
+impl<'a, 'b, V, V2, T> PartialEq<Tree<'b, V2, T>> for Tree<'a, V, T>
+where
+    T: 'a + 'b + PartialEq,
+    V: TreeVariant<'a, T>,
+    V2: TreeVariant<'b, T>,
+{
+    fn eq(&self, other: &Tree<'b, V2, T>) -> bool {
+        self.num_nodes() == other.num_nodes() && self.first_difference(other).is_none()
+    }
+}
+
+impl<'a, V, T> Eq for Tree<'a, V, T>
+where
+    T: 'a + Eq,
+    V: TreeVariant<'a, T>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        test_builder::sample,
+        tree::Tree,
+        variants::{any_ary::AnyAry, dary::Binary},
+    };
+
+    #[test]
+    fn eq() {
+        let empty = Tree::<AnyAry, char>::new();
+        let a = Tree::<AnyAry, _>::with_root('a');
+        let a2 = Tree::<Binary, _>::with_root('a');
+        let b = Tree::<AnyAry, _>::with_root('b');
+
+        assert_eq!(empty, Tree::<Binary, char>::new());
+        assert_eq!(a, a2);
+        assert_ne!(a, b);
+        assert_ne!(a, empty);
+        assert_ne!(empty, a);
+    }
+
+    #[test]
+    fn eq_binary_slots() {
+        let mut left = Tree::<Binary, _>::with_root('a');
+        left.set_child_at(&[], 0, 'b');
+        let mut right = Tree::<Binary, _>::with_root('a');
+        right.set_child_at(&[], 1, 'b');
+
+        assert_ne!(left, right);
+        assert_ne!(right, left);
+        assert_eq!(left, left);
+
+        let mut any_ary = Tree::<AnyAry, _>::with_root('a');
+        any_ary.push_child_at(&[], 'b');
+        assert_eq!(any_ary, left);
+        assert_ne!(any_ary, right);
+    }
+
+    #[test]
+    fn eq_in_depth() {
+        assert_eq!(sample(), sample());
+
+        let mut other = sample();
+        other.push_child_at(&[1, 0, 0], 'h');
+        assert_ne!(sample(), other);
+
+        let mut other = Tree::with_root('a');
+        other.push_child_at(&[], 'b');
+        other.push_child_at(&[], 'c');
+        other.push_child_at(&[0], 'd');
+        other.push_child_at(&[1], 'e');
+        other.push_child_at(&[1], 'f');
+        other.push_child_at(&[1, 1], 'g');
+        assert_eq!(sample().num_nodes(), other.num_nodes());
+        assert_ne!(sample(), other);
+    }
+}
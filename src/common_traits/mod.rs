@@ -1 +1,4 @@
+mod clone;
 mod debug;
+mod display;
+mod eq;
@@ -0,0 +1,54 @@
+use crate::queries::position_path::PositionPathError;
+use std::fmt::Display;
+
+/// Errors of the crate, each variant wrapping the error of the corresponding operation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TreeError {
+    /// Parsing a [`PositionPath`](crate::queries::position_path::PositionPath) failed.
+    PositionPath(PositionPathError),
+}
+
+impl Display for TreeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PositionPath(e) => write!(f, "invalid position path: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for TreeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::PositionPath(e) => Some(e),
+        }
+    }
+}
+
+impl From<PositionPathError> for TreeError {
+    fn from(value: PositionPathError) -> Self {
+        Self::PositionPath(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TreeError;
+    use crate::queries::position_path::PositionPath;
+    use std::error::Error;
+
+    #[test]
+    fn tree_error_from_position_path_error() {
+        fn parse(s: &str) -> Result<PositionPath, TreeError> {
+            Ok(s.parse()?)
+        }
+
+        let error = parse("0.x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid position path: segment 1 of the position path is not a valid position"
+        );
+        assert!(error.source().is_some());
+        assert_eq!(parse("0.1"), Ok(PositionPath(vec![0, 1])));
+    }
+}
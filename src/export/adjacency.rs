@@ -0,0 +1,82 @@
+use crate::{
+    traversal::depth_first::DepthFirst, tree::Tree, tree_node::TreeNode,
+    variants::tree_variant::TreeVariant,
+};
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Returns an iterator over `(node, parent)` pairs of all nodes of the subtree rooted at this node in depth first
+    /// order; the parent of this node is None since it is the root of the subtree.
+    pub fn adjacency(self) -> impl Iterator<Item = (Self, Option<Self>)> {
+        DepthFirst::new(self).map(|(depth, _, node)| match depth {
+            0 => (node, None),
+            _ => (node, node.parent()),
+        })
+    }
+}
+
+impl<'a, V, T> Tree<'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Returns an iterator over `(node, parent)` pairs of all nodes of the tree in depth first order; see
+    /// [`TreeNode::adjacency`].
+    ///
+    /// Rows are in depth first order rather than the storage order of [`Tree::iter_storage_order`]: visits over the
+    /// underlying storage can hand out values but not node handles. Each node is still visited once, and its parent
+    /// is read from the node rather than searched for.
+    pub fn adjacency(
+        &self,
+    ) -> impl Iterator<Item = (TreeNode<'_, 'a, V, T>, Option<TreeNode<'_, 'a, V, T>>)> {
+        self.root().into_iter().flat_map(TreeNode::adjacency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn adjacency() {
+        let tree = Tree::<AnyAry, char>::new();
+        assert_eq!(tree.adjacency().count(), 0);
+
+        let tree = Tree::<AnyAry, _>::with_root('a');
+        let pairs: Vec<_> = tree.adjacency().collect();
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0].0.value(), &'a');
+        assert!(pairs[0].1.is_none());
+    }
+
+    #[test]
+    fn adjacency_multi_level() {
+        let tree = sample();
+        let pairs: Vec<_> = tree
+            .adjacency()
+            .map(|(node, parent)| (*node.value(), parent.map(|x| *x.value())))
+            .collect();
+        let expected = [
+            ('a', None),
+            ('b', Some('a')),
+            ('d', Some('b')),
+            ('e', Some('b')),
+            ('c', Some('a')),
+            ('f', Some('c')),
+            ('g', Some('f')),
+        ];
+        assert_eq!(pairs, expected);
+
+        // the root of a subtree has no parent within the subtree
+        let pairs: Vec<_> = tree
+            .node_at(&[1])
+            .unwrap()
+            .adjacency()
+            .map(|(node, parent)| (*node.value(), parent.map(|x| *x.value())))
+            .collect();
+        assert_eq!(pairs, [('c', None), ('f', Some('c')), ('g', Some('f'))]);
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    traversal::depth_first::walk_with_parent_positions, tree::Tree, tree_node::TreeNode,
+    variants::tree_variant::TreeVariant,
+};
+use ego_tree::NodeId;
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a + Clone,
+    V: TreeVariant<'a, T>,
+{
+    /// Clones the subtree rooted at this node into an `ego_tree::Tree` preserving the order of children.
+    pub fn to_ego_tree(self) -> ego_tree::Tree<T> {
+        let mut ego = ego_tree::Tree::new(self.value().clone());
+        let mut ids: Vec<NodeId> = vec![ego.root().id()];
+        for (parent, node) in walk_with_parent_positions(self).skip(1) {
+            let parent = ids[parent.expect("only the root has no parent")];
+            let mut parent = ego.get_mut(parent).expect("is added before its children");
+            ids.push(parent.append(node.value().clone()).id());
+        }
+        ego
+    }
+}
+
+impl<'a, V, T> Tree<'a, V, T>
+where
+    T: 'a + Clone,
+    V: TreeVariant<'a, T>,
+{
+    /// Clones the tree into an `ego_tree::Tree` preserving the order of children;
+    /// None if the tree is empty since an `ego_tree::Tree` always has a root.
+    pub fn to_ego_tree(&self) -> Option<ego_tree::Tree<T>> {
+        self.root().map(|root| root.to_ego_tree())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn to_ego_tree() {
+        let tree = Tree::<AnyAry, char>::new();
+        assert!(tree.to_ego_tree().is_none());
+
+        let tree = Tree::<AnyAry, _>::with_root('a');
+        let ego = tree.to_ego_tree().unwrap();
+        assert_eq!(ego.root().value(), &'a');
+        assert!(!ego.root().has_children());
+    }
+
+    #[test]
+    fn to_ego_tree_multi_level() {
+        let tree = sample();
+        let ego = tree.to_ego_tree().unwrap();
+
+        let values: String = ego.root().descendants().map(|x| *x.value()).collect();
+        assert_eq!(values, "abdecfg");
+
+        let children = |v: char| -> String {
+            let node = ego.root().descendants().find(|x| *x.value() == v).unwrap();
+            node.children().map(|x| *x.value()).collect()
+        };
+        assert_eq!(children('a'), "bc");
+        assert_eq!(children('b'), "de");
+        assert_eq!(children('c'), "f");
+        assert_eq!(children('g'), "");
+
+        for node in ego.root().descendants().skip(1) {
+            let parent = *node.parent().unwrap().value();
+            assert!(children(parent).contains(*node.value()));
+        }
+        assert!(ego.root().parent().is_none());
+
+        let ego = tree.node_at(&[1]).unwrap().to_ego_tree();
+        let values: String = ego.root().descendants().map(|x| *x.value()).collect();
+        assert_eq!(values, "cfg");
+    }
+}
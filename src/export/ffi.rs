@@ -0,0 +1,86 @@
+use crate::{
+    traversal::depth_first::walk_with_parent_positions, tree::Tree, tree_node::TreeNode,
+    variants::tree_variant::TreeVariant,
+};
+
+/// Flat array representation of a tree intended to be passed across FFI boundaries;
+/// created by [`Tree::to_ffi_layout`].
+///
+/// Nodes are laid out in depth first order; the `i`-th element of each array belongs to the `i`-th node.
+/// Children of a node appear in the arrays in the order of their positions among siblings.
+pub struct FfiLayout<T> {
+    /// Position of the parent of each node; -1 for the root.
+    pub parents: Vec<i64>,
+    /// Pointer to the value of each node.
+    ///
+    /// Pointers are valid as long as the tree is neither mutated nor dropped.
+    pub data: Vec<*const T>,
+}
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Creates the flat array representation of the subtree rooted at this node; see [`FfiLayout`].
+    pub fn to_ffi_layout(self) -> FfiLayout<T> {
+        let (parents, data) = walk_with_parent_positions(self)
+            .map(|(parent, node)| {
+                let parent = parent.map(|p| p as i64).unwrap_or(-1);
+                (parent, node.value() as *const T)
+            })
+            .unzip();
+        FfiLayout { parents, data }
+    }
+}
+
+impl<'a, V, T> Tree<'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Creates the flat array representation of the tree; see [`FfiLayout`].
+    ///
+    /// Arrays are empty if the tree is empty.
+    pub fn to_ffi_layout(&self) -> FfiLayout<T> {
+        match self.root() {
+            Some(root) => root.to_ffi_layout(),
+            None => FfiLayout {
+                parents: vec![],
+                data: vec![],
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn to_ffi_layout() {
+        let tree = Tree::<AnyAry, char>::new();
+        let layout = tree.to_ffi_layout();
+        assert!(layout.parents.is_empty());
+        assert!(layout.data.is_empty());
+
+        let tree = Tree::<AnyAry, _>::with_root('a');
+        let layout = tree.to_ffi_layout();
+        assert_eq!(layout.parents, [-1]);
+        assert_eq!(unsafe { *layout.data[0] }, 'a');
+    }
+
+    #[test]
+    fn to_ffi_layout_multi_level() {
+        let tree = sample();
+        let layout = tree.to_ffi_layout();
+        assert_eq!(layout.parents, [-1, 0, 1, 1, 0, 4, 5]);
+        let values: String = layout.data.iter().map(|x| unsafe { **x }).collect();
+        assert_eq!(values, "abdecfg");
+
+        let layout = tree.node_at(&[1]).unwrap().to_ffi_layout();
+        assert_eq!(layout.parents, [-1, 0, 1]);
+        let values: String = layout.data.iter().map(|x| unsafe { **x }).collect();
+        assert_eq!(values, "cfg");
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{
+    traversal::depth_first::walk_with_parent_positions, tree::Tree, tree_node::TreeNode,
+    variants::tree_variant::TreeVariant,
+};
+use indextree::{Arena, NodeId};
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a + Clone,
+    V: TreeVariant<'a, T>,
+{
+    /// Clones the subtree rooted at this node into the given `indextree` `arena` preserving the order of children,
+    /// and returns the id of the node corresponding to this node.
+    pub fn to_indextree(self, arena: &mut Arena<T>) -> NodeId {
+        let mut ids: Vec<NodeId> = vec![];
+        for (parent, node) in walk_with_parent_positions(self) {
+            let id = arena.new_node(node.value().clone());
+            if let Some(parent) = parent {
+                ids[parent].append(id, arena);
+            }
+            ids.push(id);
+        }
+        ids[0]
+    }
+}
+
+impl<'a, V, T> Tree<'a, V, T>
+where
+    T: 'a + Clone,
+    V: TreeVariant<'a, T>,
+{
+    /// Clones the tree into a new `indextree` arena preserving the order of children, and returns the arena
+    /// together with the id of the root; None if the tree is empty.
+    pub fn to_indextree(&self) -> (Arena<T>, Option<NodeId>) {
+        let mut arena = Arena::with_capacity(self.num_nodes());
+        let root = self.root().map(|root| root.to_indextree(&mut arena));
+        (arena, root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn to_indextree() {
+        let tree = Tree::<AnyAry, char>::new();
+        let (arena, root) = tree.to_indextree();
+        assert!(arena.is_empty());
+        assert!(root.is_none());
+
+        let tree = Tree::<AnyAry, _>::with_root('a');
+        let (arena, root) = tree.to_indextree();
+        let root = root.unwrap();
+        assert_eq!(arena.len(), 1);
+        assert_eq!(arena[root].get(), &'a');
+        assert_eq!(root.children(&arena).count(), 0);
+    }
+
+    #[test]
+    fn to_indextree_multi_level() {
+        let tree = sample();
+        let (arena, root) = tree.to_indextree();
+        let root = root.unwrap();
+        assert_eq!(arena.len(), 7);
+
+        let values: String = root.descendants(&arena).map(|x| *arena[x].get()).collect();
+        assert_eq!(values, "abdecfg");
+
+        let find = |v: char| {
+            root.descendants(&arena)
+                .find(|x| *arena[*x].get() == v)
+                .unwrap()
+        };
+        let children =
+            |v: char| -> String { find(v).children(&arena).map(|x| *arena[x].get()).collect() };
+        assert_eq!(children('a'), "bc");
+        assert_eq!(children('b'), "de");
+        assert_eq!(children('c'), "f");
+        assert_eq!(children('g'), "");
+
+        let parent = |v: char| arena[find(v)].parent().map(|x| *arena[x].get());
+        assert_eq!(parent('a'), None);
+        assert_eq!(parent('b'), Some('a'));
+        assert_eq!(parent('e'), Some('b'));
+        assert_eq!(parent('c'), Some('a'));
+        assert_eq!(parent('g'), Some('f'));
+
+        let mut arena = indextree::Arena::new();
+        let c = tree.node_at(&[1]).unwrap().to_indextree(&mut arena);
+        let values: String = c.descendants(&arena).map(|x| *arena[x].get()).collect();
+        assert_eq!(values, "cfg");
+        assert!(arena[c].parent().is_none());
+    }
+}
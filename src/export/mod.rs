@@ -0,0 +1,9 @@
+pub mod adjacency;
+#[cfg(feature = "ego-tree")]
+pub mod ego_tree;
+pub mod ffi;
+#[cfg(feature = "indextree")]
+pub mod indextree;
+pub mod outline;
+#[cfg(feature = "petgraph")]
+pub mod petgraph;
@@ -0,0 +1,77 @@
+use crate::{
+    traversal::depth_first::DepthFirst, tree::Tree, tree_node::TreeNode,
+    variants::tree_variant::TreeVariant,
+};
+use std::fmt::{Display, Write};
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a + Display,
+    V: TreeVariant<'a, T>,
+{
+    /// Writes the subtree rooted at this node as a markdown nested list where each node is a line
+    /// starting with the given `bullet`, indented by two spaces per depth relative to this node.
+    pub fn to_outline(self, bullet: &str) -> String {
+        let mut outline = String::new();
+        for (depth, _, node) in DepthFirst::new(self) {
+            let indent = depth * 2;
+            _ = writeln!(outline, "{:indent$}{} {}", "", bullet, node.value());
+        }
+        outline
+    }
+}
+
+impl<'a, V, T> Tree<'a, V, T>
+where
+    T: 'a + Display,
+    V: TreeVariant<'a, T>,
+{
+    /// Writes the tree as a markdown nested list; see [`TreeNode::to_outline`].
+    ///
+    /// Returns an empty string if the tree is empty.
+    pub fn to_outline(&self, bullet: &str) -> String {
+        self.root()
+            .map(|root| root.to_outline(bullet))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn to_outline_empty() {
+        let tree = Tree::<AnyAry, char>::new();
+        assert_eq!(tree.to_outline("-"), "");
+    }
+
+    #[test]
+    fn to_outline_root_only() {
+        let tree = Tree::<AnyAry, _>::with_root("a");
+        assert_eq!(tree.to_outline("-"), "- a\n");
+        assert_eq!(tree.root().unwrap().to_outline("*"), "* a\n");
+    }
+
+    #[test]
+    fn to_outline_multi_level() {
+        let tree = sample();
+        let expected = "\
+- a
+  - b
+    - d
+    - e
+  - c
+    - f
+      - g
+";
+        assert_eq!(tree.to_outline("-"), expected);
+
+        let expected = "\
+* c
+  * f
+    * g
+";
+        assert_eq!(tree.node_at(&[1]).unwrap().to_outline("*"), expected);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    traversal::depth_first::walk_with_parent_positions, tree::Tree, tree_node::TreeNode,
+    variants::tree_variant::TreeVariant,
+};
+use petgraph::graph::{DiGraph, NodeIndex};
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Converts the subtree rooted at this node into a petgraph directed graph with an edge from each parent
+    /// to each of its children.
+    ///
+    /// Nodes are added to the graph in depth first order; i.e., the `i`-th node in depth first order of the
+    /// subtree has the graph index `NodeIndex::new(i)`, and this node has index zero.
+    pub fn to_petgraph(self) -> DiGraph<&'b T, ()> {
+        let mut graph = DiGraph::new();
+        for (parent, node) in walk_with_parent_positions(self) {
+            let idx = graph.add_node(node.value());
+            if let Some(parent) = parent {
+                graph.add_edge(NodeIndex::new(parent), idx, ());
+            }
+        }
+        graph
+    }
+}
+
+impl<'a, V, T> Tree<'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Converts the tree into a petgraph directed graph; see [`TreeNode::to_petgraph`].
+    ///
+    /// Returns an empty graph if the tree is empty.
+    pub fn to_petgraph(&self) -> DiGraph<&T, ()> {
+        self.root()
+            .map(|root| root.to_petgraph())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+    use petgraph::{graph::NodeIndex, Direction};
+
+    #[test]
+    fn to_petgraph() {
+        let tree = Tree::<AnyAry, char>::new();
+        assert_eq!(tree.to_petgraph().node_count(), 0);
+
+        let tree = Tree::<AnyAry, _>::with_root('a');
+        let graph = tree.to_petgraph();
+        assert_eq!(graph.node_count(), 1);
+        assert_eq!(graph.edge_count(), 0);
+        assert_eq!(graph.raw_nodes()[0].weight, &'a');
+    }
+
+    #[test]
+    fn to_petgraph_multi_level() {
+        let tree = sample();
+        let graph = tree.to_petgraph();
+        assert_eq!(graph.node_count(), 7);
+        assert_eq!(graph.edge_count(), 6);
+
+        let values: String = graph.raw_nodes().iter().map(|x| *x.weight).collect();
+        assert_eq!(values, "abdecfg");
+
+        let edges: Vec<_> = graph
+            .raw_edges()
+            .iter()
+            .map(|e| (*graph[e.source()], *graph[e.target()]))
+            .collect();
+        assert_eq!(
+            edges,
+            [
+                ('a', 'b'),
+                ('b', 'd'),
+                ('b', 'e'),
+                ('a', 'c'),
+                ('c', 'f'),
+                ('f', 'g')
+            ]
+        );
+
+        let parent = |i: usize| {
+            let mut parents = graph.neighbors_directed(NodeIndex::new(i), Direction::Incoming);
+            parents.next().map(|p| *graph[p])
+        };
+        assert_eq!(parent(0), None);
+        assert_eq!(parent(3), Some('b'));
+        assert_eq!(parent(6), Some('f'));
+
+        let graph = tree.node_at(&[1]).unwrap().to_petgraph();
+        let values: String = graph.raw_nodes().iter().map(|x| *x.weight).collect();
+        assert_eq!(values, "cfg");
+        assert_eq!(graph.edge_count(), 2);
+    }
+}
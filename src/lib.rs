@@ -1,5 +1,19 @@
+mod algorithms;
+mod any_tree;
 mod common_traits;
+mod errors;
+mod export;
 mod mutations;
+mod queries;
+mod traversal;
 mod tree;
 mod tree_node;
+mod tree_view;
+mod validation;
 mod variants;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(test)]
+mod test_builder;
@@ -0,0 +1,87 @@
+use crate::{tree::Tree, variants::tree_variant::TreeVariant};
+use orx_selfref_col::{Node, Reclaim, SelfRefCol, SelfRefColMut};
+use orx_split_vec::{Recursive, SplitVec};
+
+impl<'a, V, T> Tree<'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Removes all nodes from the tree and returns an iterator over their values.
+    ///
+    /// Values are taken in the order the nodes are laid out in memory, which is not necessarily a traversal order;
+    /// the tree is empty once this method returns.
+    pub fn drain(&mut self) -> impl Iterator<Item = T> {
+        let mut values = vec![];
+        if !self.is_empty() {
+            values = V::take_values(self);
+            self.col.clear();
+            self.bump_structural_version();
+            self.validate_after_mutation();
+        }
+        values.into_iter()
+    }
+}
+
+/// Closes all nodes of the `col` and returns their values in storage order; the nodes are not reclaimed.
+pub(crate) fn take_values<'a, V, T>(
+    col: &mut SelfRefCol<'a, V, T, SplitVec<Node<'a, V, T>, Recursive>>,
+) -> Vec<T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+    for<'rf> SelfRefColMut<'rf, 'a, V, T, SplitVec<Node<'a, V, T>, Recursive>>:
+        Reclaim<V::Prev, V::Next>,
+{
+    let mut values = Vec::with_capacity(col.len());
+    let mut collect = |value| values.push(value);
+    // mutation lambdas are function pointers which cannot capture `values`; `mutate_filter_collect` is the mutation
+    // passing a collector of taken values into the lambda. Its filter is meant to decide which nodes to keep; since
+    // it is always false here, every active node is closed and its value is handed to the collector.
+    col.mutate_filter_collect(&|_: &T| false, &mut collect, |x, keep, collect| {
+        for i in 0..x.len() {
+            let node = x.get_node(i).expect("index is in bounds");
+            if node.data().is_some_and(|value| !keep(value)) {
+                collect(node.close_node_take_data_no_reclaim(&x));
+            }
+        }
+    });
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::dary::Binary};
+
+    #[test]
+    fn drain() {
+        let mut tree: Tree<Binary, char> = Tree::new();
+        assert_eq!(tree.drain().count(), 0);
+        assert_eq!(tree.structural_version(), 0);
+
+        let mut tree: Tree<Binary, _> = Tree::with_root(String::from("a"));
+        assert_eq!(tree.drain().collect::<Vec<_>>(), ["a"]);
+        assert!(tree.is_empty());
+        assert!(tree.root().is_none());
+        assert_eq!(tree.structural_version(), 2);
+
+        tree.insert_root(String::from("b"));
+        assert_eq!(tree.root().unwrap().value(), "b");
+    }
+
+    #[test]
+    fn drain_multi_level() {
+        let mut tree = sample();
+        let storage_order: String = tree.iter_storage_order().collect();
+        let version = tree.structural_version();
+
+        let values: String = tree.drain().collect();
+        assert_eq!(values.len(), 7);
+        assert_eq!(values, storage_order);
+        assert!(tree.is_empty());
+        assert_eq!(tree.num_nodes(), 0);
+        assert!(tree.root().is_none());
+        assert_eq!(tree.iter_dfs().count(), 0);
+        assert_eq!(tree.structural_version(), version + 1);
+    }
+}
@@ -1,21 +1,25 @@
-use crate::{tree::Tree, tree_node::TreeNode, variants::tree_variant::TreeVariant};
+use crate::{
+    tree::Tree,
+    tree_node::TreeNode,
+    variants::tree_variant::{TreeEnds, TreeVariant},
+};
 use std::marker::PhantomData;
 
-pub enum Insertion<'a, V, T>
+pub enum Insertion<'b, 'a, V, T>
 where
     T: 'a,
     V: TreeVariant<'a, T>,
 {
     None,
-    AsParentOf(TreeNode<'a, V, T>),
-    AsChildOf(TreeNode<'a, V, T>, usize),
+    AsParentOf(TreeNode<'b, 'a, V, T>),
+    AsChildOf(TreeNode<'b, 'a, V, T>, usize),
 }
 
 pub struct Insert<'a, V, T, Fun>
 where
     T: 'a,
     V: TreeVariant<'a, T>,
-    Fun: Fn(TreeNode<'a, V, T>) -> Insertion<'a, V, T>,
+    Fun: for<'b> Fn(TreeNode<'b, 'a, V, T>) -> Insertion<'b, 'a, V, T>,
 {
     fun: Fun,
     phantom: PhantomData<&'a (V, T)>,
@@ -28,12 +32,13 @@ where
 {
     pub fn insert<Fun>(&mut self, search_insertion: Fun, value: T)
     where
-        Fun: Fn(TreeNode<'a, V, T>) -> Insertion<'a, V, T>,
+        Fun: for<'b> Fn(TreeNode<'b, 'a, V, T>) -> Insertion<'b, 'a, V, T>,
     {
-        match self.root() {
+        // the handle does not borrow the tree since the insertion is consumed by the variant within this call
+        match self.col.ends().root() {
             None => self.insert_root(value),
             Some(root) => {
-                let insertion = search_insertion(root);
+                let insertion = search_insertion(TreeNode::new(root));
                 V::insert(self, insertion);
             }
         }
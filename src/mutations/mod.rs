@@ -1 +1,3 @@
+pub mod drain;
 pub mod insert;
+pub mod replace;
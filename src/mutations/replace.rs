@@ -0,0 +1,44 @@
+use crate::{
+    tree::Tree,
+    variants::tree_variant::{TreeEnds, TreeVariant},
+};
+
+impl<'a, V, T> Tree<'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Replaces the value of the root with the given `value` keeping its children, and returns the prior value.
+    ///
+    /// If the tree is empty, `value` becomes the root of the tree and None is returned.
+    pub fn replace_root(&mut self, value: T) -> Option<T> {
+        match self.is_empty() {
+            true => {
+                self.insert_root(value);
+                None
+            }
+            false => Some(self.col.move_mutate_take(value, |x, value| {
+                let root = x.ends().root().expect("tree is not empty");
+                root.swap_data(&x, value)
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tree::Tree, variants::dary::Binary};
+
+    #[test]
+    fn replace_root() {
+        let mut tree: Tree<Binary, _> = Tree::new();
+
+        assert_eq!(tree.replace_root('a'), None);
+        assert_eq!(tree.num_nodes(), 1);
+        assert_eq!(tree.root().unwrap().value(), &'a');
+
+        assert_eq!(tree.replace_root('b'), Some('a'));
+        assert_eq!(tree.num_nodes(), 1);
+        assert_eq!(tree.root().unwrap().value(), &'b');
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{tree_node::TreeNode, variants::tree_variant::TreeVariant};
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Returns the depth of this node relative to the given `ancestor`; i.e., the number of edges from the
+    /// `ancestor` down to this node. Returns zero if the `ancestor` is this node itself, and None if it is not
+    /// an ancestor of this node.
+    ///
+    /// Time complexity is linear in the depth of this node.
+    pub fn relative_depth_to(&self, ancestor: &Self) -> Option<usize> {
+        self.ancestors_and_self()
+            .position(|node| node.ref_eq(ancestor))
+    }
+
+    /// Returns whether or not this node lies on the path between nodes `a` and `b`, both ends inclusive.
+    /// Returns false if `a` and `b` do not belong to the same tree.
+    ///
+    /// Time complexity is linear in the depths of the nodes.
+    pub fn is_on_path_between(&self, a: &Self, b: &Self) -> bool {
+        let Some(lca) = a.lowest_common_ancestor(b) else {
+            return false;
+        };
+        match self.relative_depth_to(&lca) {
+            Some(_) => a.relative_depth_to(self).is_some() || b.relative_depth_to(self).is_some(),
+            None => false,
+        }
+    }
+
+    // helpers
+    fn ancestors_and_self(&self) -> impl Iterator<Item = Self> {
+        std::iter::successors(Some(*self), |node| node.parent())
+    }
+
+    fn lowest_common_ancestor(&self, other: &Self) -> Option<Self> {
+        let (mut a, mut b) = (*self, *other);
+        let (depth_a, depth_b) = (
+            self.ancestors_and_self().count(),
+            other.ancestors_and_self().count(),
+        );
+        for _ in depth_b..depth_a {
+            a = a.parent()?;
+        }
+        for _ in depth_a..depth_b {
+            b = b.parent()?;
+        }
+        while !a.ref_eq(&b) {
+            (a, b) = (a.parent()?, b.parent()?);
+        }
+        Some(a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn relative_depth_and_path_between_root_only() {
+        let tree = Tree::<AnyAry, _>::with_root('a');
+        let root = tree.root().unwrap();
+        assert_eq!(root.relative_depth_to(&root), Some(0));
+        assert!(root.is_on_path_between(&root, &root));
+
+        let other = Tree::<AnyAry, _>::with_root('a');
+        let other = other.root().unwrap();
+        assert_eq!(root.relative_depth_to(&other), None);
+        assert!(!root.is_on_path_between(&root, &other));
+    }
+
+    #[test]
+    fn relative_depth_to() {
+        let tree = sample();
+        let node = |path: &[u32]| tree.node_at(path).unwrap();
+        let (a, b, c, g) = (node(&[]), node(&[0]), node(&[1]), node(&[1, 0, 0]));
+
+        assert_eq!(g.relative_depth_to(&a), Some(3));
+        assert_eq!(g.relative_depth_to(&c), Some(2));
+        assert_eq!(g.relative_depth_to(&g), Some(0));
+        // descendant and node in a different branch
+        assert_eq!(c.relative_depth_to(&g), None);
+        assert_eq!(g.relative_depth_to(&b), None);
+        assert_eq!(node(&[0, 1]).relative_depth_to(&b), Some(1));
+    }
+
+    #[test]
+    fn is_on_path_between() {
+        let tree = sample();
+        let node = |path: &[u32]| tree.node_at(path).unwrap();
+        let on_path =
+            |x: &[u32], a: &[u32], b: &[u32]| node(x).is_on_path_between(&node(a), &node(b));
+
+        // d -> b -> a -> c -> f -> g crossing branches, in both directions
+        for x in [&[0, 0][..], &[0], &[], &[1], &[1, 0], &[1, 0, 0]] {
+            assert!(on_path(x, &[0, 0], &[1, 0, 0]));
+            assert!(on_path(x, &[1, 0, 0], &[0, 0]));
+        }
+        assert!(!on_path(&[0, 1], &[0, 0], &[1, 0, 0]));
+
+        // ancestor and descendant, in both directions
+        for x in [&[1][..], &[1, 0], &[1, 0, 0]] {
+            assert!(on_path(x, &[1], &[1, 0, 0]));
+            assert!(on_path(x, &[1, 0, 0], &[1]));
+        }
+        assert!(!on_path(&[], &[1], &[1, 0, 0]));
+        assert!(!on_path(&[0], &[1], &[1, 0, 0]));
+
+        // siblings meet at their parent, excluding its ancestors
+        assert!(on_path(&[0], &[0, 0], &[0, 1]));
+        assert!(!on_path(&[], &[0, 0], &[0, 1]));
+        assert!(!on_path(&[1, 0], &[0, 0], &[0, 1]));
+    }
+}
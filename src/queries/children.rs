@@ -0,0 +1,138 @@
+use crate::{
+    tree_node::TreeNode,
+    variants::{dary::Dary, tree_variant::TreeVariant},
+};
+use orx_selfref_col::NodeRefs;
+use std::collections::VecDeque;
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Returns an iterator over the values of the children of this node, without creating a [`TreeNode`] per child.
+    pub fn children_data(&self) -> impl Iterator<Item = &'b T> + use<'b, 'a, V, T> {
+        self.node
+            .next()
+            .referenced_nodes()
+            .map(|node| unsafe { node.data().unwrap_unchecked() })
+    }
+
+    /// Returns an iterator over the children of this node in chunks of `chunk_size` children;
+    /// the last chunk might be shorter.
+    ///
+    /// Children are read lazily; however, each yielded chunk is a newly allocated vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn children_chunks(&self, chunk_size: usize) -> impl Iterator<Item = Vec<Self>> {
+        assert!(chunk_size > 0, "chunk size must be positive");
+        let mut children = self.children();
+        std::iter::from_fn(move || {
+            let chunk: Vec<_> = children.by_ref().take(chunk_size).collect();
+            (!chunk.is_empty()).then_some(chunk)
+        })
+    }
+
+    /// Returns an iterator over all contiguous windows of `window_size` children of this node;
+    /// yields nothing if the node has fewer than `window_size` children.
+    ///
+    /// Children are read lazily into a buffer of `window_size` nodes; however, each yielded window is a newly
+    /// allocated vector copied from the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window_size` is zero.
+    pub fn children_windows(&self, window_size: usize) -> impl Iterator<Item = Vec<Self>> {
+        assert!(window_size > 0, "window size must be positive");
+        let mut children = self.children();
+        let mut window = VecDeque::with_capacity(window_size);
+        std::iter::from_fn(move || {
+            if window.len() == window_size {
+                window.pop_front();
+            }
+            while window.len() < window_size {
+                window.push_back(children.next()?);
+            }
+            Some(window.iter().copied().collect())
+        })
+    }
+}
+
+impl<'b, 'a, const N: usize, T> TreeNode<'b, 'a, Dary<N>, T>
+where
+    T: 'a,
+{
+    /// Returns the `N` child slots of this node by position; a slot is None if there is no child at that position.
+    pub fn children_array(&self) -> [Option<Self>; N] {
+        self.node.next().get().map(|child| child.map(Self::new))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tree::Tree,
+        tree_node::TreeNode,
+        variants::{any_ary::AnyAry, dary::Ternary},
+    };
+
+    fn values(nodes: Vec<TreeNode<'_, '_, AnyAry, char>>) -> String {
+        nodes.into_iter().map(|x| *x.value()).collect()
+    }
+
+    #[test]
+    fn children_chunks_and_windows() {
+        let mut tree: Tree<AnyAry, _> = Tree::with_root('r');
+        for child in ['a', 'b', 'c', 'd', 'e'] {
+            tree.push_child_at(&[], child);
+        }
+        let root = tree.root().unwrap();
+
+        let chunks: Vec<_> = root.children_chunks(2).map(values).collect();
+        assert_eq!(chunks, ["ab", "cd", "e"]);
+        let chunks: Vec<_> = root.children_chunks(5).map(values).collect();
+        assert_eq!(chunks, ["abcde"]);
+        let chunks: Vec<_> = root.children_chunks(7).map(values).collect();
+        assert_eq!(chunks, ["abcde"]);
+
+        let windows: Vec<_> = root.children_windows(3).map(values).collect();
+        assert_eq!(windows, ["abc", "bcd", "cde"]);
+        let windows: Vec<_> = root.children_windows(1).map(values).collect();
+        assert_eq!(windows, ["a", "b", "c", "d", "e"]);
+        let windows: Vec<_> = root.children_windows(5).map(values).collect();
+        assert_eq!(windows, ["abcde"]);
+        assert_eq!(root.children_windows(6).count(), 0);
+    }
+
+    #[test]
+    fn children_chunks_and_windows_without_children() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        let root = tree.root().unwrap();
+        assert_eq!(root.children_data().count(), 0);
+        assert_eq!(root.children_chunks(2).count(), 0);
+        assert_eq!(root.children_windows(1).count(), 0);
+    }
+
+    #[test]
+    fn children_array_without_children() {
+        let tree: Tree<Ternary, _> = Tree::with_root('a');
+        let children = tree.root().unwrap().children_array();
+        assert!(children.iter().all(Option::is_none));
+    }
+
+    #[test]
+    #[should_panic]
+    fn children_chunks_zero() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        _ = tree.root().unwrap().children_chunks(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn children_windows_zero() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        _ = tree.root().unwrap().children_windows(0);
+    }
+}
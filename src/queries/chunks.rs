@@ -0,0 +1,129 @@
+use crate::{
+    traversal::depth_first::nodes_with_subtree_sizes, tree_node::TreeNode,
+    variants::tree_variant::TreeVariant,
+};
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Splits the subtree rooted at this node into `k` groups of disjoint subtree roots such that the total
+    /// number of nodes in the subtrees of each group are approximately balanced.
+    ///
+    /// Subtrees larger than `ceil(n / k)` nodes are split into the subtrees of their children, where `n` is the
+    /// size of the subtree rooted at this node. The nodes which are split this way do not belong to any group;
+    /// these are the nodes on the paths from this node to the subtree roots. Every other node of the subtree
+    /// belongs to the subtree of exactly one of the returned roots.
+    ///
+    /// Resulting subtrees are then assigned, largest first, to the group with the least total size.
+    /// Some of the groups might be empty when the subtree cannot be split into `k` subtrees.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is zero.
+    pub fn split_into_approx_chunks(&self, k: usize) -> Vec<Vec<TreeNode<'b, 'a, V, T>>> {
+        assert!(k > 0, "number of chunks must be positive");
+
+        let (nodes, sizes) = nodes_with_subtree_sizes(*self);
+        let max_chunk_size = sizes[0].div_ceil(k);
+
+        let mut roots = vec![];
+        let mut stack = vec![0];
+        while let Some(position) = stack.pop() {
+            let end = position + sizes[position];
+            match sizes[position] <= max_chunk_size || end == position + 1 {
+                true => roots.push(position),
+                false => {
+                    let len = stack.len();
+                    let mut child = position + 1;
+                    while child < end {
+                        stack.push(child);
+                        child += sizes[child];
+                    }
+                    stack[len..].reverse();
+                }
+            }
+        }
+        roots.sort_by_key(|x| Reverse(sizes[*x]));
+
+        let mut groups = vec![vec![]; k];
+        let mut totals: BinaryHeap<_> = (0..k).map(|g| Reverse((0, g))).collect();
+        for root in roots {
+            let Reverse((total, g)) = totals.pop().expect("k is positive");
+            groups[g].push(nodes[root]);
+            totals.push(Reverse((total + sizes[root], g)));
+        }
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn split_into_approx_chunks_root_only() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        let root = tree.root().unwrap();
+
+        let chunks = root.split_into_approx_chunks(1);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(*chunks[0][0].value(), 'a');
+
+        let chunks = root.split_into_approx_chunks(3);
+        assert_eq!(chunks.iter().map(|x| x.len()).sum::<usize>(), 1);
+    }
+
+    #[test]
+    fn split_into_approx_chunks_across_depths() {
+        // a
+        // ├──b
+        // │  ├──d
+        // │  ├──e
+        // │  └──h
+        // └──c
+        let mut tree: Tree<AnyAry, _> = Tree::with_root('a');
+        tree.push_child_at(&[], 'b');
+        tree.push_child_at(&[], 'c');
+        tree.push_child_at(&[0], 'd');
+        tree.push_child_at(&[0], 'e');
+        tree.push_child_at(&[0], 'h');
+        let root = tree.root().unwrap();
+
+        // a and b are split; c at depth 1 shares a chunk with e at depth 2 below a different parent
+        let chunks: Vec<String> = root
+            .split_into_approx_chunks(2)
+            .into_iter()
+            .map(|chunk| chunk.into_iter().map(|x| *x.value()).collect())
+            .collect();
+        assert_eq!(chunks, ["dh", "ec"]);
+    }
+
+    #[test]
+    fn split_into_approx_chunks_multi_level() {
+        let tree = sample();
+        let root = tree.root().unwrap();
+        let chunks = |k| -> Vec<String> {
+            let chunks = root.split_into_approx_chunks(k).into_iter();
+            chunks
+                .map(|chunk| chunk.into_iter().map(|x| *x.value()).collect())
+                .collect()
+        };
+
+        assert_eq!(chunks(1), ["a"]);
+        // subtrees of the siblings b and c, 3 nodes each
+        assert_eq!(chunks(2), ["b", "c"]);
+        // f at depth 2 below c is not split further while both b and c are
+        assert_eq!(chunks(4), ["f", "d", "e", ""]);
+        assert_eq!(chunks(7), ["d", "e", "g", "", "", "", ""]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_into_approx_chunks_zero() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        _ = tree.root().unwrap().split_into_approx_chunks(0);
+    }
+}
@@ -0,0 +1,228 @@
+use crate::{tree::Tree, tree_node::TreeNode, variants::tree_variant::TreeVariant};
+
+/// Kind of the first mismatch found while comparing two trees.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DivergenceKind {
+    /// Both trees have a node at the path, but with different values.
+    Data,
+    /// The other tree has a node at the path which this tree does not have.
+    MissingNode,
+    /// This tree has a node at the path which the other tree does not have.
+    ExtraNode,
+}
+
+/// First point, in depth first order, where two trees differ.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DivergencePoint {
+    /// Positions among siblings of the nodes on the path from the root to the diverging node;
+    /// empty if the trees differ at the root.
+    ///
+    /// Positions are child slots for variants with a fixed number of children, such as
+    /// [`Binary`](crate::variants::dary::Binary), where a missing left child leaves its slot empty.
+    pub path: Vec<usize>,
+    /// Kind of the mismatch.
+    pub kind: DivergenceKind,
+}
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Compares the subtrees rooted at this node and the `other` node, and returns the first point of divergence
+    /// in depth first order; None if the subtrees are equal.
+    ///
+    /// The path of the divergence point is relative to these nodes.
+    pub fn first_difference<'c, V2>(
+        &self,
+        other: &TreeNode<'_, 'c, V2, T>,
+    ) -> Option<DivergencePoint>
+    where
+        T: PartialEq,
+        V2: TreeVariant<'c, T>,
+    {
+        first_difference(Some(*self), Some(*other))
+    }
+}
+
+impl<'a, V, T> Tree<'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Compares this tree with the `other` and returns the first point of divergence in depth first order;
+    /// None if the trees are equal.
+    pub fn first_difference<'b, V2>(&self, other: &Tree<'b, V2, T>) -> Option<DivergencePoint>
+    where
+        T: PartialEq,
+        V2: TreeVariant<'b, T>,
+    {
+        first_difference(self.root(), other.root())
+    }
+}
+
+fn first_difference<'a, 'b, V, V2, T>(
+    root: Option<TreeNode<'_, 'a, V, T>>,
+    other_root: Option<TreeNode<'_, 'b, V2, T>>,
+) -> Option<DivergencePoint>
+where
+    T: 'a + 'b + PartialEq,
+    V: TreeVariant<'a, T>,
+    V2: TreeVariant<'b, T>,
+{
+    let mut path = vec![];
+    let mut stack = vec![(0usize, 0, root, other_root)];
+    while let Some((depth, sibling_idx, node, other)) = stack.pop() {
+        path.truncate(depth.saturating_sub(1));
+        if depth > 0 {
+            path.push(sibling_idx);
+        }
+
+        let (node, other) = match (node, other) {
+            (Some(node), Some(other)) => (node, other),
+            (None, None) => continue,
+            (None, Some(_)) => {
+                let kind = DivergenceKind::MissingNode;
+                return Some(DivergencePoint { path, kind });
+            }
+            (Some(_), None) => {
+                let kind = DivergenceKind::ExtraNode;
+                return Some(DivergencePoint { path, kind });
+            }
+        };
+
+        if node.value() != other.value() {
+            let kind = DivergenceKind::Data;
+            return Some(DivergencePoint { path, kind });
+        }
+
+        let len = stack.len();
+        let mut slots = node.child_slots();
+        let mut other_slots = other.child_slots();
+        for i in 0.. {
+            match (slots.next(), other_slots.next()) {
+                (None, None) => break,
+                (child, other_child) => {
+                    stack.push((depth + 1, i, child.flatten(), other_child.flatten()))
+                }
+            }
+        }
+        stack[len..].reverse();
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_builder::sample,
+        variants::{any_ary::AnyAry, dary::Binary},
+    };
+
+    #[test]
+    fn first_difference_of_roots() {
+        let empty = Tree::<AnyAry, char>::new();
+        let a = Tree::<AnyAry, _>::with_root('a');
+        let a2 = Tree::<Binary, _>::with_root('a');
+        let b = Tree::<AnyAry, _>::with_root('b');
+
+        assert_eq!(empty.first_difference(&Tree::<Binary, char>::new()), None);
+        assert_eq!(a.first_difference(&a2), None);
+        assert_eq!(
+            a.root().unwrap().first_difference(&a2.root().unwrap()),
+            None
+        );
+
+        let point = |kind| Some(DivergencePoint { path: vec![], kind });
+        assert_eq!(a.first_difference(&b), point(DivergenceKind::Data));
+        assert_eq!(
+            empty.first_difference(&a),
+            point(DivergenceKind::MissingNode)
+        );
+        assert_eq!(a.first_difference(&empty), point(DivergenceKind::ExtraNode));
+    }
+
+    #[test]
+    fn first_difference_in_depth() {
+        let tree = sample();
+        assert_eq!(tree.first_difference(&sample()), None);
+
+        let mut other = Tree::with_root('a');
+        other.push_child_at(&[], 'b');
+        other.push_child_at(&[], 'c');
+        other.push_child_at(&[0], 'd');
+        other.push_child_at(&[0], 'x');
+        let point = DivergencePoint {
+            path: vec![0, 1],
+            kind: DivergenceKind::Data,
+        };
+        assert_eq!(tree.first_difference(&other), Some(point));
+
+        let mut other = sample();
+        other.push_child_at(&[1, 0, 0], 'h');
+        let point = |kind| {
+            let path = vec![1, 0, 0, 0];
+            Some(DivergencePoint { path, kind })
+        };
+        assert_eq!(
+            tree.first_difference(&other),
+            point(DivergenceKind::MissingNode)
+        );
+        assert_eq!(
+            other.first_difference(&tree),
+            point(DivergenceKind::ExtraNode)
+        );
+
+        let b = tree.node_at(&[0]).unwrap();
+        let c = other.node_at(&[1]).unwrap();
+        assert_eq!(b.first_difference(&b), None);
+        let point = DivergencePoint {
+            path: vec![],
+            kind: DivergenceKind::Data,
+        };
+        assert_eq!(b.first_difference(&c), Some(point));
+    }
+
+    #[test]
+    fn first_difference_of_child_counts() {
+        let tree = sample();
+
+        let mut other = sample();
+        other.push_child_at(&[], 'x');
+        let point = |path, kind| Some(DivergencePoint { path, kind });
+        let missing = DivergenceKind::MissingNode;
+        assert_eq!(tree.first_difference(&other), point(vec![2], missing));
+
+        let mut other = Tree::with_root('a');
+        other.push_child_at(&[], 'b');
+        other.push_child_at(&[0], 'd');
+        let extra = DivergenceKind::ExtraNode;
+        assert_eq!(tree.first_difference(&other), point(vec![0, 1], extra));
+    }
+
+    #[test]
+    fn first_difference_of_binary_slots() {
+        let mut left = Tree::<Binary, _>::with_root('a');
+        left.set_child_at(&[], 0, 'b');
+        let mut right = Tree::<Binary, _>::with_root('a');
+        right.set_child_at(&[], 1, 'b');
+
+        let point = |path, kind| Some(DivergencePoint { path, kind });
+        let extra = DivergenceKind::ExtraNode;
+        let missing = DivergenceKind::MissingNode;
+        assert_eq!(left.first_difference(&right), point(vec![0], extra));
+        assert_eq!(right.first_difference(&left), point(vec![0], missing));
+
+        let mut any_ary = Tree::<AnyAry, _>::with_root('a');
+        any_ary.push_child_at(&[], 'b');
+        assert_eq!(any_ary.first_difference(&left), None);
+        assert_eq!(any_ary.first_difference(&right), point(vec![0], extra));
+
+        right.set_child_at(&[1], 1, 'c');
+        let mut other = Tree::<Binary, _>::with_root('a');
+        other.set_child_at(&[], 1, 'b');
+        other.set_child_at(&[1], 0, 'c');
+        assert_eq!(right.first_difference(&other), point(vec![1, 0], missing));
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{
+    traversal::{custom_walk::WalkControl, depth_first::DepthFirst},
+    tree::Tree,
+    tree_node::TreeNode,
+    variants::tree_variant::TreeVariant,
+};
+use orx_selfref_col::Node;
+use std::collections::HashSet;
+
+/// Non-destructive view of a tree exposing only the nodes satisfying a predicate together with their ancestors;
+/// created by [`TreeNode::filter_view`] or [`Tree::filter_view`].
+///
+/// The view is computed in a single bottom-up pass over the subtree and does not mutate or copy the tree.
+pub struct FilteredTreeView<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    root: Option<TreeNode<'b, 'a, V, T>>,
+    kept: HashSet<*const Node<'a, V, T>>,
+}
+
+impl<'b, 'a, V, T> FilteredTreeView<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    fn new<P>(root: Option<TreeNode<'b, 'a, V, T>>, mut predicate: P) -> Self
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let nodes: Vec<_> = root
+            .into_iter()
+            .flat_map(DepthFirst::new)
+            .map(|(_, _, node)| node)
+            .collect();
+
+        // children follow their parents in depth first order
+        let mut kept = HashSet::new();
+        for (position, node) in nodes.iter().enumerate().rev() {
+            if kept.contains(&(node.node as *const _)) || predicate(node.value()) {
+                kept.insert(node.node as *const _);
+                if position > 0 {
+                    let parent = node
+                        .parent()
+                        .expect("non-root node of the walk has a parent");
+                    kept.insert(parent.node as *const _);
+                }
+            }
+        }
+
+        let root = root.filter(|root| kept.contains(&(root.node as *const _)));
+        Self { root, kept }
+    }
+
+    /// Number of nodes in the view.
+    pub fn len(&self) -> usize {
+        self.kept.len()
+    }
+
+    /// Returns whether or not the view is empty, which is the case when no node satisfies the predicate.
+    pub fn is_empty(&self) -> bool {
+        self.kept.is_empty()
+    }
+
+    /// Returns the root of the view; None if the view is empty.
+    pub fn root(&self) -> Option<TreeNode<'b, 'a, V, T>> {
+        self.root
+    }
+
+    /// Returns whether or not the `node` is in the view.
+    pub fn contains(&self, node: &TreeNode<'_, 'a, V, T>) -> bool {
+        self.kept.contains(&(node.node as *const _))
+    }
+
+    /// Returns an iterator over the children of the `node` which are in the view.
+    pub fn children<'n>(
+        &'n self,
+        node: &TreeNode<'n, 'a, V, T>,
+    ) -> impl Iterator<Item = TreeNode<'n, 'a, V, T>> + 'n {
+        node.children().filter(|child| self.contains(child))
+    }
+
+    /// Walks the nodes of the view in depth first order; each item is a `(depth, node)` pair where the depth of
+    /// the root is zero. Subtrees which are not in the view are not visited.
+    pub fn walk(&self) -> impl Iterator<Item = (usize, TreeNode<'b, 'a, V, T>)> + '_ {
+        let control = |_, node| match self.contains(&node) {
+            true => WalkControl::Descend,
+            false => WalkControl::Skip,
+        };
+        let nodes = self
+            .root
+            .into_iter()
+            .flat_map(move |root| root.custom_walk(control));
+        nodes.filter(|(_, node)| self.contains(node))
+    }
+}
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Creates a view of the subtree rooted at this node exposing only the nodes whose values satisfy the
+    /// `predicate`, together with their ancestors up to this node; see [`FilteredTreeView`].
+    pub fn filter_view<P>(&self, predicate: P) -> FilteredTreeView<'b, 'a, V, T>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        FilteredTreeView::new(Some(*self), predicate)
+    }
+}
+
+impl<'a, V, T> Tree<'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Creates a view of the tree exposing only the nodes whose values satisfy the `predicate`, together with
+    /// their ancestors; see [`FilteredTreeView`].
+    pub fn filter_view<P>(&self, predicate: P) -> FilteredTreeView<'_, 'a, V, T>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        FilteredTreeView::new(self.root(), predicate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn filter_view_empty() {
+        let tree = Tree::<AnyAry, char>::new();
+        let view = tree.filter_view(|_| true);
+        assert!(view.is_empty());
+        assert!(view.root().is_none());
+        assert_eq!(view.walk().count(), 0);
+    }
+
+    #[test]
+    fn filter_view_root_only() {
+        let tree = Tree::<AnyAry, _>::with_root('a');
+        let root = tree.root().unwrap();
+
+        let view = tree.filter_view(|x| *x == 'a');
+        assert_eq!(view.len(), 1);
+        assert!(view.contains(&root));
+        assert_eq!(
+            view.walk()
+                .map(|(d, n)| (d, *n.value()))
+                .collect::<Vec<_>>(),
+            [(0, 'a')]
+        );
+
+        let view = tree.filter_view(|x| *x == 'b');
+        assert!(view.is_empty());
+        assert!(!view.contains(&root));
+        assert!(view.root().is_none());
+    }
+
+    #[test]
+    fn filter_view_keeps_ancestors() {
+        let tree = sample();
+        let root = tree.root().unwrap();
+        let view = tree.filter_view(|x| *x == 'g');
+        assert_eq!(view.len(), 4);
+
+        let walk: Vec<_> = view.walk().map(|(d, n)| (d, *n.value())).collect();
+        assert_eq!(walk, [(0, 'a'), (1, 'c'), (2, 'f'), (3, 'g')]);
+
+        let children: String = view.children(&root).map(|x| *x.value()).collect();
+        assert_eq!(children, "c");
+        let b = tree.node_at(&[0]).unwrap();
+        assert!(!view.contains(&b));
+        assert_eq!(view.children(&b).count(), 0);
+
+        let view = tree.filter_view(|x| matches!(x, 'd' | 'f'));
+        let walk: String = view.walk().map(|(_, n)| *n.value()).collect();
+        assert_eq!(walk, "abdcf");
+        let children: String = view.children(&root).map(|x| *x.value()).collect();
+        assert_eq!(children, "bc");
+    }
+
+    #[test]
+    fn filter_view_of_subtree() {
+        let tree = sample();
+        let c = tree.node_at(&[1]).unwrap();
+
+        // the ancestors are kept up to the root of the view only
+        let view = c.filter_view(|x| *x == 'g');
+        assert_eq!(view.root().map(|x| *x.value()), Some('c'));
+        let walk: Vec<_> = view.walk().map(|(d, n)| (d, *n.value())).collect();
+        assert_eq!(walk, [(0, 'c'), (1, 'f'), (2, 'g')]);
+        assert!(!view.contains(&tree.root().unwrap()));
+
+        // nodes outside of the subtree are never in the view
+        let view = c.filter_view(|x| *x == 'd');
+        assert!(view.is_empty());
+        assert!(view.root().is_none());
+    }
+}
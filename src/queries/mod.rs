@@ -0,0 +1,11 @@
+pub mod ancestry;
+pub mod children;
+pub mod chunks;
+pub mod divergence;
+pub mod filter_view;
+pub mod paths;
+pub mod position_path;
+pub mod search;
+pub mod siblings;
+pub mod stats;
+pub mod subtree_numbering;
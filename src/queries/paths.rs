@@ -0,0 +1,130 @@
+use crate::{
+    traversal::depth_first::DepthFirst, tree_node::TreeNode, variants::tree_variant::TreeVariant,
+};
+
+/// Iterator over paths from a node to the leaves of its subtree, which stops after a maximum number of paths;
+/// created by [`TreeNode::paths_limited`].
+pub struct PathsLimited<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    walk: DepthFirst<'b, 'a, V, T>,
+    path: Vec<&'b T>,
+    remaining: usize,
+    truncated: bool,
+}
+
+impl<'b, 'a, V, T> PathsLimited<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Returns whether or not the iterator stopped before yielding all paths due to the limit.
+    ///
+    /// The result is meaningful once the iterator returned None.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+impl<'b, 'a, V, T> Iterator for PathsLimited<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    type Item = Vec<&'b T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            // each subtree left in the stack has at least one leaf
+            self.truncated = self.walk.storage_len() > 0;
+            return None;
+        }
+
+        for (depth, _, node) in self.walk.by_ref() {
+            self.path.truncate(depth);
+            self.path.push(node.value());
+            if node.children().next().is_none() {
+                self.remaining -= 1;
+                return Some(self.path.clone());
+            }
+        }
+        None
+    }
+}
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Returns the number of paths from this node to the leaves of its subtree; i.e., the number of leaves.
+    pub fn num_paths(&self) -> usize {
+        DepthFirst::new(*self)
+            .filter(|(_, _, node)| node.children().next().is_none())
+            .count()
+    }
+
+    /// Returns an iterator over the paths from this node to the leaves of its subtree in depth first order,
+    /// yielding at most `max_paths` paths.
+    ///
+    /// Each path is the list of values of the nodes from this node down to a leaf.
+    /// Once the iterator is consumed, [`PathsLimited::is_truncated`] tells whether or not there were more paths.
+    pub fn paths_limited(&self, max_paths: usize) -> PathsLimited<'b, 'a, V, T> {
+        PathsLimited {
+            walk: DepthFirst::new(*self),
+            path: vec![],
+            remaining: max_paths,
+            truncated: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn num_paths_root_only() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        assert_eq!(tree.root().unwrap().num_paths(), 1);
+    }
+
+    #[test]
+    fn paths_limited_root_only() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        let root = tree.root().unwrap();
+
+        let mut paths = root.paths_limited(0);
+        assert_eq!(paths.next(), None);
+        assert!(paths.is_truncated());
+
+        let mut paths = root.paths_limited(1);
+        assert_eq!(paths.next(), Some(vec![&'a']));
+        assert_eq!(paths.next(), None);
+        assert!(!paths.is_truncated());
+    }
+
+    #[test]
+    fn paths_limited_multi_level() {
+        let tree = sample();
+        let root = tree.root().unwrap();
+        assert_eq!(root.num_paths(), 3);
+        assert_eq!(tree.node_at(&[0]).unwrap().num_paths(), 2);
+
+        let paths = |max_paths| {
+            let mut iter = root.paths_limited(max_paths);
+            let paths: Vec<String> = iter
+                .by_ref()
+                .map(|path| path.into_iter().collect())
+                .collect();
+            (paths, iter.is_truncated())
+        };
+        assert_eq!(paths(1), (vec!["abd".to_string()], true));
+        assert_eq!(paths(2), (vec!["abd".to_string(), "abe".to_string()], true));
+        let all = vec!["abd".to_string(), "abe".to_string(), "acfg".to_string()];
+        assert_eq!(paths(3), (all.clone(), false));
+        assert_eq!(paths(4), (all, false));
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{tree::Tree, tree_node::TreeNode, variants::tree_variant::TreeVariant};
+use std::{fmt::Display, str::FromStr};
+
+/// Positions among siblings of the nodes on the path from the root to a node, excluding the root.
+///
+/// For variants with a fixed number of children, such as [`Binary`](crate::variants::dary::Binary), the position
+/// of a node is its child slot; hence, the only child of a node in its right slot is at position `1`.
+///
+/// A position path identifies a node by the shape of the tree only, and hence, it can be persisted and resolved
+/// against an equal tree later, even when node values are not unique.
+/// Its compact string form joins the positions with dots, such as `"0.3.1"`; the path of the root is the empty string.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PositionPath(pub Vec<u32>);
+
+/// Error of parsing a [`PositionPath`] from its string form.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PositionPathError {
+    /// Index of the dot-separated segment which is not a valid position.
+    pub segment: usize,
+}
+
+impl Display for PositionPathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "segment {} of the position path is not a valid position",
+            self.segment
+        )
+    }
+}
+
+impl std::error::Error for PositionPathError {}
+
+impl From<Vec<u32>> for PositionPath {
+    fn from(positions: Vec<u32>) -> Self {
+        Self(positions)
+    }
+}
+
+impl Display for PositionPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, position) in self.0.iter().enumerate() {
+            match i {
+                0 => write!(f, "{}", position)?,
+                _ => write!(f, ".{}", position)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for PositionPath {
+    type Err = PositionPathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.is_empty() {
+            true => Ok(Self::default()),
+            false => s
+                .split('.')
+                .enumerate()
+                .map(|(segment, x)| x.parse().map_err(|_| PositionPathError { segment }))
+                .collect::<Result<_, _>>()
+                .map(Self),
+        }
+    }
+}
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Returns the positions among siblings of the nodes on the path from the root of the tree to this node;
+    /// see [`PositionPath`].
+    ///
+    /// Time complexity is linear in the total number of siblings of the nodes on the path.
+    pub fn position_path(&self) -> PositionPath {
+        let mut path = vec![];
+        let mut node = *self;
+        while let Some(parent) = node.parent() {
+            let position = parent
+                .child_slots()
+                .position(|x| x.is_some_and(|x| x.ref_eq(&node)));
+            path.push(position.expect("node is a child of its parent") as u32);
+            node = parent;
+        }
+        path.reverse();
+        PositionPath(path)
+    }
+}
+
+impl<'a, V, T> Tree<'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Returns the node at the given position `path` from the root; None if there is no such node.
+    /// See [`PositionPath`].
+    pub fn node_at_position_path(&self, path: &PositionPath) -> Option<TreeNode<'_, 'a, V, T>> {
+        let mut node = self.root()?;
+        for position in &path.0 {
+            node = node.child_slots().nth(*position as usize).flatten()?;
+        }
+        Some(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_builder::sample,
+        traversal::depth_first::DepthFirst,
+        variants::{any_ary::AnyAry, dary::Binary},
+    };
+
+    #[test]
+    fn position_path_root() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        let root = tree.root().unwrap();
+        assert_eq!(root.position_path(), PositionPath::default());
+        assert_eq!(
+            tree.node_at_position_path(&PositionPath(vec![]))
+                .map(|x| *x.value()),
+            Some('a')
+        );
+        assert!(tree.node_at_position_path(&PositionPath(vec![0])).is_none());
+        assert!(Tree::<AnyAry, char>::new()
+            .node_at_position_path(&PositionPath(vec![]))
+            .is_none());
+    }
+
+    #[test]
+    fn position_path_in_depth() {
+        let tree = sample();
+        let g = tree
+            .node_at_position_path(&PositionPath(vec![1, 0, 0]))
+            .unwrap();
+        assert_eq!(g.value(), &'g');
+        assert_eq!(g.position_path(), PositionPath(vec![1, 0, 0]));
+
+        let e = tree
+            .node_at_position_path(&PositionPath(vec![0, 1]))
+            .unwrap();
+        assert_eq!(e.value(), &'e');
+        assert_eq!(e.position_path(), PositionPath(vec![0, 1]));
+
+        let f = tree.node_at_position_path(&"1.0".parse().unwrap()).unwrap();
+        assert_eq!(f.value(), &'f');
+        assert_eq!(f.position_path().to_string(), "1.0");
+
+        assert!(tree
+            .node_at_position_path(&PositionPath(vec![0, 2]))
+            .is_none());
+        assert!(tree
+            .node_at_position_path(&PositionPath(vec![1, 0, 0, 0]))
+            .is_none());
+        for (_, _, node) in DepthFirst::new(tree.root().unwrap()) {
+            let path = node.position_path();
+            assert!(tree.node_at_position_path(&path).unwrap().ref_eq(&node));
+        }
+    }
+
+    #[test]
+    fn position_path_binary_slots() {
+        let mut tree = Tree::<Binary, _>::with_root('a');
+        tree.set_child_at(&[], 1, 'b');
+        tree.set_child_at(&[1], 0, 'c');
+        tree.set_child_at(&[1], 1, 'd');
+        tree.set_child_at(&[1, 1], 1, 'e');
+
+        let b = tree.node_at_position_path(&PositionPath(vec![1])).unwrap();
+        assert_eq!(b.value(), &'b');
+        assert_eq!(b.position_path(), PositionPath(vec![1]));
+        assert!(tree.node_at_position_path(&PositionPath(vec![0])).is_none());
+        assert!(tree.node_at_position_path(&PositionPath(vec![2])).is_none());
+
+        let e = tree
+            .node_at_position_path(&PositionPath(vec![1, 1, 1]))
+            .unwrap();
+        assert_eq!(e.value(), &'e');
+        assert_eq!(e.position_path(), PositionPath(vec![1, 1, 1]));
+        assert!(tree
+            .node_at_position_path(&PositionPath(vec![1, 1, 0]))
+            .is_none());
+    }
+
+    #[test]
+    fn position_path_string() {
+        for positions in [vec![], vec![0], vec![0, 3, 1], vec![42, 7]] {
+            let path = PositionPath(positions);
+            assert_eq!(path.to_string().parse(), Ok(path));
+        }
+        assert_eq!(PositionPath(vec![0, 3, 1]).to_string(), "0.3.1");
+        assert_eq!(PositionPath::default().to_string(), "");
+
+        let error = |segment| Err(PositionPathError { segment });
+        assert_eq!("0.x.1".parse::<PositionPath>(), error(1));
+        assert_eq!("0..1".parse::<PositionPath>(), error(1));
+        assert_eq!("-1".parse::<PositionPath>(), error(0));
+        assert_eq!("1.".parse::<PositionPath>(), error(1));
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{
+    traversal::depth_first::DepthFirst, tree_node::TreeNode, variants::tree_variant::TreeVariant,
+};
+use std::fmt::Debug;
+
+/// Order in which nodes are visited while searching for a node.
+#[allow(clippy::enum_variant_names)]
+pub enum SearchOrder<'s, T> {
+    /// Depth first (pre-order) search.
+    DepthFirst,
+    /// Breadth first (level order) search.
+    BreadthFirst,
+    /// Best first search visiting the discovered node with the highest score next; see
+    /// [`BestFirst`](crate::traversal::best_first::BestFirst).
+    BestFirst(&'s dyn Fn(&T) -> i64),
+}
+
+impl<T> Clone for SearchOrder<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SearchOrder<'_, T> {}
+
+impl<T> Debug for SearchOrder<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DepthFirst => write!(f, "DepthFirst"),
+            Self::BreadthFirst => write!(f, "BreadthFirst"),
+            Self::BestFirst(_) => write!(f, "BestFirst(..)"),
+        }
+    }
+}
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Returns whether or not the subtree rooted at this node contains a node with the given `value`.
+    ///
+    /// The search is depth first and terminates at the first match.
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.find_value(value).is_some()
+    }
+
+    /// Returns whether or not the subtree rooted at this node contains a node with the given `value`;
+    /// see [`TreeNode::find_value_with`].
+    pub fn contains_with(&self, value: &T, walk: &mut DepthFirst<'b, 'a, V, T>) -> bool
+    where
+        T: PartialEq,
+    {
+        self.find_value_with(value, walk).is_some()
+    }
+
+    /// Returns the first node, in depth first order, of the subtree rooted at this node having the given `value`;
+    /// None if there is no such node.
+    pub fn find_value(&self, value: &T) -> Option<Self>
+    where
+        T: PartialEq,
+    {
+        self.find_value_with(value, &mut self.walk_dfs())
+    }
+
+    /// Returns the first node, in depth first order, of the subtree rooted at this node having the given `value`;
+    /// None if there is no such node.
+    ///
+    /// The search restarts the given `walk` from this node, and hence, reuses its stack rather than allocating
+    /// a new one; this is useful when searching repeatedly.
+    pub fn find_value_with(&self, value: &T, walk: &mut DepthFirst<'b, 'a, V, T>) -> Option<Self>
+    where
+        T: PartialEq,
+    {
+        walk.restart(*self);
+        walk.map(|(_, _, node)| node)
+            .find(|node| node.value() == value)
+    }
+
+    /// Returns the node of the subtree rooted at this node with the maximum `score`;
+    /// the first one in depth first order in case of ties.
+    pub fn find_max_by_key<K, S>(&self, score: S) -> Option<Self>
+    where
+        K: Ord,
+        S: Fn(&T) -> K,
+    {
+        let mut best: Option<(K, Self)> = None;
+        for (_, _, node) in DepthFirst::new(*self) {
+            let key = score(node.value());
+            if best.as_ref().map(|(x, _)| key > *x).unwrap_or(true) {
+                best = Some((key, node));
+            }
+        }
+        best.map(|(_, node)| node)
+    }
+
+    /// Returns the first node of the subtree rooted at this node satisfying the `predicate`
+    /// when the nodes are visited in the given `order`.
+    pub fn find_first_where<P>(&self, predicate: P, order: SearchOrder<'_, T>) -> Option<Self>
+    where
+        P: Fn(&T) -> bool,
+    {
+        match order {
+            SearchOrder::DepthFirst => self
+                .walk_dfs()
+                .map(|(_, _, node)| node)
+                .find(|node| predicate(node.value())),
+            SearchOrder::BreadthFirst => self
+                .walk_bfs()
+                .map(|(_, _, node)| node)
+                .find(|node| predicate(node.value())),
+            SearchOrder::BestFirst(score) => self.find_first_where_best_first(predicate, score),
+        }
+    }
+
+    /// Returns the first node of the subtree rooted at this node satisfying the `predicate`
+    /// when the nodes are visited in best first order.
+    ///
+    /// Among the nodes discovered so far, the one with the highest `score` is visited next, ties being broken
+    /// by the order of discovery; see [`TreeNode::walk_best_first`].
+    pub fn find_first_where_best_first<P, K, S>(&self, predicate: P, score: S) -> Option<Self>
+    where
+        P: Fn(&T) -> bool,
+        K: Ord,
+        S: Fn(&T) -> K,
+    {
+        self.walk_best_first(score)
+            .map(|(_, _, node)| node)
+            .find(|node| predicate(node.value()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn contains() {
+        let tree: Tree<AnyAry, _> = Tree::with_root(42);
+        let root = tree.root().unwrap();
+        assert!(root.contains(&42));
+        assert!(!root.contains(&7));
+    }
+
+    #[test]
+    fn find_value() {
+        let tree: Tree<AnyAry, _> = Tree::with_root(42);
+        let root = tree.root().unwrap();
+        assert_eq!(root.find_value(&42).map(|x| *x.value()), Some(42));
+        assert!(root.find_value(&7).is_none());
+    }
+
+    #[test]
+    fn find_value_with() {
+        let tree = sample();
+        let root = tree.root().unwrap();
+        let b = tree.node_at(&[0]).unwrap();
+
+        let mut walk = root.walk_dfs();
+        assert_eq!(
+            root.find_value_with(&'g', &mut walk)
+                .map(|x| x.position_path().to_string()),
+            Some("1.0.0".to_string())
+        );
+        assert!(!b.contains_with(&'g', &mut walk));
+        assert!(b.contains_with(&'e', &mut walk));
+        assert!(root.contains_with(&'a', &mut walk));
+        assert!(!root.contains_with(&'x', &mut walk));
+    }
+
+    #[test]
+    fn find_max_by_key() {
+        let tree: Tree<AnyAry, i32> = Tree::with_root(-3);
+        let root = tree.root().unwrap();
+        assert_eq!(
+            root.find_max_by_key(|x| x.abs()).map(|x| *x.value()),
+            Some(-3)
+        );
+    }
+
+    #[test]
+    fn find_first_where() {
+        let tree: Tree<AnyAry, _> = Tree::with_root(42);
+        let root = tree.root().unwrap();
+        for order in [SearchOrder::DepthFirst, SearchOrder::BreadthFirst] {
+            assert!(root.find_first_where(|x| x % 2 == 0, order).is_some());
+            assert!(root.find_first_where(|x| x % 2 == 1, order).is_none());
+        }
+        assert!(root
+            .find_first_where_best_first(|x| *x == 42, |x| *x)
+            .is_some());
+        assert!(root
+            .find_first_where_best_first(|x| *x == 7, |x| *x)
+            .is_none());
+    }
+
+    #[test]
+    fn find_first_where_in_order() {
+        let tree = sample();
+        let root = tree.root().unwrap();
+        let find = |order| {
+            root.find_first_where(|x| *x > 'b', order)
+                .map(|x| *x.value())
+        };
+        assert_eq!(find(SearchOrder::DepthFirst), Some('d'));
+        assert_eq!(find(SearchOrder::BreadthFirst), Some('c'));
+        assert_eq!(find(SearchOrder::BestFirst(&|x| *x as i64)), Some('c'));
+
+        let prefer_b_subtree = |x: &char| matches!(x, 'b' | 'd' | 'e') as i64;
+        assert_eq!(find(SearchOrder::BestFirst(&prefer_b_subtree)), Some('d'));
+    }
+}
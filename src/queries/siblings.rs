@@ -0,0 +1,109 @@
+use crate::{tree_node::TreeNode, variants::tree_variant::TreeVariant};
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Returns an iterator over the siblings of this node, excluding the node itself, from left to right;
+    /// empty if this node is the root.
+    pub fn siblings(&self) -> impl Iterator<Item = Self> {
+        let node = *self;
+        self.all_siblings().filter(move |x| !x.ref_eq(&node))
+    }
+
+    /// Returns an iterator over the siblings to the left of this node from left to right.
+    pub fn left_siblings(&self) -> impl Iterator<Item = Self> {
+        let node = *self;
+        self.all_siblings().take_while(move |x| !x.ref_eq(&node))
+    }
+
+    /// Returns an iterator over the siblings to the right of this node from left to right.
+    pub fn right_siblings(&self) -> impl Iterator<Item = Self> {
+        let node = *self;
+        self.all_siblings()
+            .skip_while(move |x| !x.ref_eq(&node))
+            .skip(1)
+    }
+
+    /// Returns the sibling immediately to the right of this node; None if this is the last child or the root.
+    pub fn next_sibling(&self) -> Option<Self> {
+        self.right_siblings().next()
+    }
+
+    /// Returns the sibling immediately to the left of this node; None if this is the first child or the root.
+    pub fn prev_sibling(&self) -> Option<Self> {
+        self.left_siblings().last()
+    }
+
+    // helpers
+    fn all_siblings(&self) -> impl Iterator<Item = Self> {
+        self.parent()
+            .into_iter()
+            .flat_map(|parent| parent.children())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, tree_node::TreeNode, variants::any_ary::AnyAry};
+
+    #[test]
+    fn siblings_of_root() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        let root = tree.root().unwrap();
+        assert_eq!(root.siblings().count(), 0);
+        assert_eq!(root.left_siblings().count(), 0);
+        assert_eq!(root.right_siblings().count(), 0);
+        assert!(root.next_sibling().is_none());
+        assert!(root.prev_sibling().is_none());
+    }
+
+    fn values<'b>(nodes: impl Iterator<Item = TreeNode<'b, 'static, AnyAry, char>>) -> String {
+        nodes.map(|x| *x.value()).collect()
+    }
+
+    #[test]
+    fn siblings_of_first_middle_and_last_children() {
+        // a
+        // ├──b
+        // │  ├──d
+        // │  ├──e
+        // │  └──h
+        // └──c
+        //    └──f
+        //       └──g
+        let mut tree = sample();
+        tree.push_child_at(&[0], 'h');
+        let siblings = |path: &[u32]| {
+            let node = tree.node_at(path).unwrap();
+            let prev = node.prev_sibling().map(|x| *x.value());
+            let next = node.next_sibling().map(|x| *x.value());
+            let left = values(node.left_siblings());
+            let right = values(node.right_siblings());
+            (values(node.siblings()), left, right, prev, next)
+        };
+        let expected = |all: &str, left: &str, right: &str, prev, next| {
+            (
+                all.to_string(),
+                left.to_string(),
+                right.to_string(),
+                prev,
+                next,
+            )
+        };
+
+        // first, middle and last children of b
+        assert_eq!(siblings(&[0, 0]), expected("eh", "", "eh", None, Some('e')));
+        assert_eq!(
+            siblings(&[0, 1]),
+            expected("dh", "d", "h", Some('d'), Some('h'))
+        );
+        assert_eq!(siblings(&[0, 2]), expected("de", "de", "", Some('e'), None));
+        // children of the root
+        assert_eq!(siblings(&[0]), expected("c", "", "c", None, Some('c')));
+        assert_eq!(siblings(&[1]), expected("b", "b", "", Some('b'), None));
+        // only child
+        assert_eq!(siblings(&[1, 0]), expected("", "", "", None, None));
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{
+    traversal::depth_first::DepthFirst, tree::Tree, tree_node::TreeNode,
+    variants::tree_variant::TreeVariant,
+};
+use std::fmt::Display;
+
+/// Summary statistics of the shape of a tree or subtree; created by [`Tree::stats`] or [`TreeNode::stats`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TreeStats {
+    /// Number of nodes.
+    pub len: usize,
+    /// Depth of the deepest node, where the root has depth zero; zero for an empty tree.
+    pub height: usize,
+    /// Number of nodes without children.
+    pub leaves: usize,
+    /// Maximum number of children of a node.
+    pub max_branching: usize,
+    /// Average number of children of the nodes having at least one child; zero if there is no such node.
+    pub avg_branching: f64,
+    /// Number of nodes at each depth; the `d`-th element is the number of nodes at depth `d`.
+    pub depth_histogram: Vec<usize>,
+}
+
+impl Display for TreeStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "nodes: {}, height: {}, leaves: {}, max branching: {}, avg branching: {:.2}",
+            self.len, self.height, self.leaves, self.max_branching, self.avg_branching
+        )?;
+        write!(f, "depth histogram: {:?}", self.depth_histogram)
+    }
+}
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Computes summary statistics of the subtree rooted at this node in a single pass; see [`TreeStats`].
+    pub fn stats(&self) -> TreeStats {
+        let mut stats = TreeStats::default();
+        let mut num_internal = 0;
+        let mut num_children_of_internal = 0;
+        for (depth, _, node) in DepthFirst::new(*self) {
+            stats.len += 1;
+            if stats.depth_histogram.len() == depth {
+                stats.depth_histogram.push(0);
+            }
+            stats.depth_histogram[depth] += 1;
+
+            match node.children().count() {
+                0 => stats.leaves += 1,
+                num_children => {
+                    num_internal += 1;
+                    num_children_of_internal += num_children;
+                    stats.max_branching = stats.max_branching.max(num_children);
+                }
+            }
+        }
+        stats.height = stats.depth_histogram.len().saturating_sub(1);
+        if num_internal > 0 {
+            stats.avg_branching = num_children_of_internal as f64 / num_internal as f64;
+        }
+        stats
+    }
+}
+
+impl<'a, V, T> Tree<'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Computes summary statistics of the tree in a single pass; see [`TreeStats`].
+    pub fn stats(&self) -> TreeStats {
+        self.root().map(|root| root.stats()).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_builder::sample, variants::any_ary::AnyAry};
+
+    #[test]
+    fn stats_empty() {
+        let tree = Tree::<AnyAry, char>::new();
+        assert_eq!(tree.stats(), TreeStats::default());
+    }
+
+    #[test]
+    fn stats_root_only() {
+        let tree = Tree::<AnyAry, _>::with_root('a');
+        let stats = tree.stats();
+        let expected = TreeStats {
+            len: 1,
+            height: 0,
+            leaves: 1,
+            max_branching: 0,
+            avg_branching: 0.0,
+            depth_histogram: vec![1],
+        };
+        assert_eq!(stats, expected);
+        assert_eq!(tree.root().unwrap().stats(), expected);
+        assert_eq!(
+            stats.to_string(),
+            "nodes: 1, height: 0, leaves: 1, max branching: 0, avg branching: 0.00\ndepth histogram: [1]"
+        );
+    }
+
+    #[test]
+    fn stats_multi_level() {
+        let tree = sample();
+        let expected = TreeStats {
+            len: 7,
+            height: 3,
+            leaves: 3,
+            max_branching: 2,
+            avg_branching: 1.5,
+            depth_histogram: vec![1, 2, 3, 1],
+        };
+        assert_eq!(tree.stats(), expected);
+        assert_eq!(
+            tree.stats().to_string(),
+            "nodes: 7, height: 3, leaves: 3, max branching: 2, avg branching: 1.50\ndepth histogram: [1, 2, 3, 1]"
+        );
+
+        let c = tree.node_at(&[1]).unwrap();
+        let expected = TreeStats {
+            len: 3,
+            height: 2,
+            leaves: 1,
+            max_branching: 1,
+            avg_branching: 1.0,
+            depth_histogram: vec![1, 1, 1],
+        };
+        assert_eq!(c.stats(), expected);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{
+    traversal::depth_first::DepthFirst, tree_node::TreeNode, variants::tree_variant::TreeVariant,
+};
+use orx_selfref_col::Node;
+use std::collections::HashMap;
+
+/// Numbering of the nodes of a subtree with `0..len` in depth first (pre-order) order, with lookups in both
+/// directions; created by [`TreeNode::enumerate_subtree`].
+///
+/// The root of the subtree is numbered zero. Numbers are local to the subtree and can be used to index
+/// per-subtree matrices or bitsets.
+pub struct SubtreeNumbering<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    nodes: Vec<TreeNode<'b, 'a, V, T>>,
+    numbers: HashMap<*const Node<'a, V, T>, usize>,
+}
+
+impl<'b, 'a, V, T> SubtreeNumbering<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Number of nodes of the subtree.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns whether or not the numbering is empty; never the case since the subtree contains at least its root.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the node with the given `number`; None if the number is out of bounds.
+    pub fn node(&self, number: usize) -> Option<TreeNode<'b, 'a, V, T>> {
+        self.nodes.get(number).copied()
+    }
+
+    /// Returns the number of the `node`; None if the node does not belong to the subtree.
+    pub fn number_of(&self, node: &TreeNode<'b, 'a, V, T>) -> Option<usize> {
+        self.numbers.get(&(node.node as *const _)).copied()
+    }
+}
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Numbers the nodes of the subtree rooted at this node with `0..len` in depth first order;
+    /// see [`SubtreeNumbering`].
+    pub fn enumerate_subtree(&self) -> SubtreeNumbering<'b, 'a, V, T> {
+        let nodes: Vec<_> = DepthFirst::new(*self).map(|(_, _, node)| node).collect();
+        let numbers = nodes
+            .iter()
+            .enumerate()
+            .map(|(number, node)| (node.node as *const _, number))
+            .collect();
+        SubtreeNumbering { nodes, numbers }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn enumerate_subtree_root_only() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        let root = tree.root().unwrap();
+        let numbering = root.enumerate_subtree();
+        assert_eq!(numbering.len(), 1);
+        assert_eq!(numbering.number_of(&root), Some(0));
+        assert_eq!(numbering.node(0).map(|x| *x.value()), Some('a'));
+        assert!(numbering.node(1).is_none());
+
+        let other: Tree<AnyAry, _> = Tree::with_root('a');
+        assert_eq!(numbering.number_of(&other.root().unwrap()), None);
+    }
+
+    #[test]
+    fn enumerate_subtree_multi_level() {
+        let tree = sample();
+        let c = tree.node_at(&[1]).unwrap();
+        let numbering = c.enumerate_subtree();
+        assert_eq!(numbering.len(), 3);
+
+        let values: String = (0..3)
+            .map(|i| *numbering.node(i).unwrap().value())
+            .collect();
+        assert_eq!(values, "cfg");
+        assert!(numbering.node(3).is_none());
+
+        assert_eq!(numbering.number_of(&c), Some(0));
+        assert_eq!(
+            numbering.number_of(&tree.node_at(&[1, 0]).unwrap()),
+            Some(1)
+        );
+        assert_eq!(
+            numbering.number_of(&tree.node_at(&[1, 0, 0]).unwrap()),
+            Some(2)
+        );
+
+        // nodes outside of the subtree have no number
+        assert_eq!(numbering.number_of(&tree.root().unwrap()), None);
+        assert_eq!(numbering.number_of(&tree.node_at(&[0, 1]).unwrap()), None);
+    }
+}
@@ -0,0 +1,106 @@
+//! Builders of trees for unit tests, creating nodes directly on the underlying collection.
+//!
+//! Nodes are addressed by paths from the root where each element is the position of the child among its
+//! siblings for [`AnyAry`], and the slot of the child for [`Dary`] variants.
+
+use crate::{
+    queries::position_path::PositionPath,
+    tree::Tree,
+    tree_node::TreeNode,
+    variants::{
+        any_ary::AnyAry,
+        dary::Dary,
+        tree_variant::{TreeEnds, TreeVariant},
+    },
+};
+use orx_selfref_col::NodeRefs;
+
+impl<'a, V, T> Tree<'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Returns the node at the position `path`; see [`Tree::node_at_position_path`].
+    pub(crate) fn node_at(&self, path: &[u32]) -> Option<TreeNode<'_, 'a, V, T>> {
+        self.node_at_position_path(&PositionPath(path.to_vec()))
+    }
+}
+
+impl<'a, T: 'a> Tree<'a, AnyAry, T> {
+    /// Pushes a node with the given `value` as the last child of the node at the `path`.
+    pub(crate) fn push_child_at(&mut self, path: &[usize], value: T) {
+        self.col
+            .move_mutate((path.to_vec(), value), |x, (path, value)| {
+                let mut parent = x.ends().root().expect("tree is not empty");
+                for position in path {
+                    parent = parent.next().get()[position];
+                }
+                let child = x.push_get_ref(value);
+                parent.next_vec_mut(&x).push(child);
+                child.set_prev(&x, parent);
+            });
+    }
+
+    /// Pushes the `values` as a chain of nodes, each one the only child of the previous one, below the node at
+    /// the `path`.
+    pub(crate) fn push_chain_at(&mut self, path: &[usize], values: Vec<T>) {
+        self.col
+            .move_mutate((path.to_vec(), values), |x, (path, values)| {
+                let mut parent = x.ends().root().expect("tree is not empty");
+                for position in path {
+                    parent = parent.next().get()[position];
+                }
+                for value in values {
+                    let child = x.push_get_ref(value);
+                    parent.next_vec_mut(&x).push(child);
+                    child.set_prev(&x, parent);
+                    parent = child;
+                }
+            });
+    }
+}
+
+impl<'a, const N: usize, T: 'a> Tree<'a, Dary<N>, T> {
+    /// Sets a node with the given `value` as the child at the `slot` of the node at the `path`.
+    pub(crate) fn set_child_at(&mut self, path: &[usize], slot: usize, value: T) {
+        self.col
+            .move_mutate((path.to_vec(), slot, value), |x, (path, slot, value)| {
+                let mut parent = x.ends().root().expect("tree is not empty");
+                for slot in path {
+                    parent = parent.next().get()[slot].expect("slot is occupied");
+                }
+                let child = x.push_get_ref(value);
+                parent.next_array_mut(&x)[slot] = Some(child);
+                child.set_prev(&x, parent);
+            });
+    }
+}
+
+/// Builds the tree
+///
+/// ```text
+/// a
+/// ├──b
+/// │  ├──d
+/// │  └──e
+/// └──c
+///    └──f
+///       └──g
+/// ```
+pub(crate) fn sample() -> Tree<'static, AnyAry, char> {
+    let mut tree = Tree::with_root('a');
+    tree.push_child_at(&[], 'b');
+    tree.push_child_at(&[], 'c');
+    tree.push_child_at(&[0], 'd');
+    tree.push_child_at(&[0], 'e');
+    tree.push_child_at(&[1], 'f');
+    tree.push_child_at(&[1, 0], 'g');
+    tree
+}
+
+/// Builds a tree of `len` nodes with values `0..len` where each node is the only child of the previous one.
+pub(crate) fn chain(len: usize) -> Tree<'static, AnyAry, usize> {
+    let mut tree = Tree::with_root(0);
+    tree.push_chain_at(&[], (1..len).collect());
+    tree
+}
@@ -0,0 +1,97 @@
+//! Helpers for snapshot testing trees, available with the `testing` feature.
+//!
+//! The canonical snapshot of a tree is its `Display` rendering: one node per line, children drawn below
+//! their parent with `├──`, `└──` and `│` guides.
+
+/// Normalizes a tree snapshot so that it can be written as an indented string literal in tests.
+///
+/// * leading and trailing blank lines are removed;
+/// * trailing whitespace of each line is removed;
+/// * the indentation of the first line, which is the root, is removed from every line;
+/// * each line is terminated by a new line.
+pub fn normalize_snapshot(snapshot: &str) -> String {
+    let lines: Vec<_> = snapshot.lines().map(str::trim_end).collect();
+    let begin = lines.iter().position(|x| !x.is_empty());
+    let end = lines.iter().rposition(|x| !x.is_empty());
+    let (begin, end) = match (begin, end) {
+        (Some(begin), Some(end)) => (begin, end),
+        _ => return String::new(),
+    };
+
+    let root = lines[begin];
+    let indent = root.len() - root.trim_start().len();
+    let mut normalized = String::new();
+    for line in &lines[begin..=end] {
+        let line = match line.get(..indent) {
+            Some(x) if x.trim().is_empty() => &line[indent..],
+            _ => line,
+        };
+        normalized.push_str(line);
+        normalized.push('\n');
+    }
+    normalized
+}
+
+/// Asserts that the snapshot of a tree or node is equal to the expected snapshot; see [`normalize_snapshot`].
+///
+/// ```ignore
+/// assert_tree_eq!(
+///     tree,
+///     r#"
+///     a
+///     ├──b
+///     └──c
+///     "#
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_tree_eq {
+    ($tree:expr, $expected:expr $(,)?) => {{
+        let actual = $crate::testing::normalize_snapshot(&$tree.to_string());
+        let expected = $crate::testing::normalize_snapshot($expected);
+        assert!(
+            actual == expected,
+            "trees are not equal\n--- actual ---\n{}--- expected ---\n{}",
+            actual,
+            expected
+        );
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn normalize() {
+        assert_eq!(normalize_snapshot(""), "");
+        assert_eq!(normalize_snapshot("\n   \n"), "");
+        assert_eq!(normalize_snapshot("a"), "a\n");
+        assert_eq!(normalize_snapshot("a  \n└──b \t"), "a\n└──b\n");
+
+        let snapshot = "
+            a
+            ├──b
+            │  └──d
+            └──c
+               └──e
+        ";
+        let expected = "a\n├──b\n│  └──d\n└──c\n   └──e\n";
+        assert_eq!(normalize_snapshot(snapshot), expected);
+    }
+
+    #[test]
+    fn assert_tree_eq_root_only() {
+        let tree = Tree::<AnyAry, _>::with_root('a');
+        assert_tree_eq!(tree, "a");
+        assert_tree_eq!(tree.root().unwrap(), "\n    a\n");
+    }
+
+    #[test]
+    #[should_panic]
+    fn assert_tree_eq_different() {
+        let tree = Tree::<AnyAry, _>::with_root('a');
+        assert_tree_eq!(tree, "b");
+    }
+}
@@ -0,0 +1,170 @@
+use crate::{tree_node::TreeNode, variants::tree_variant::TreeVariant};
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+};
+
+/// Iterator over the nodes of a subtree in best first order.
+///
+/// Among the nodes discovered so far, the one with the highest score is visited next, ties being broken
+/// by the order of discovery. Children of a node are discovered only once the node is visited.
+///
+/// Each item is a `(depth, sibling_idx, node)` tuple where the depth and sibling index of the root
+/// of the walk are both zero.
+pub struct BestFirst<'b, 'a, V, T, K, S>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+    K: Ord,
+    S: Fn(&T) -> K,
+{
+    heap: BinaryHeap<Scored<K, TreeNode<'b, 'a, V, T>>>,
+    score: S,
+    num_discovered: usize,
+}
+
+impl<'b, 'a, V, T, K, S> BestFirst<'b, 'a, V, T, K, S>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+    K: Ord,
+    S: Fn(&T) -> K,
+{
+    pub(crate) fn new(root: TreeNode<'b, 'a, V, T>, score: S) -> Self {
+        let mut walk = Self {
+            heap: BinaryHeap::new(),
+            score,
+            num_discovered: 0,
+        };
+        walk.discover(0, 0, root);
+        walk
+    }
+
+    /// Number of nodes currently waiting in the heap of the walk.
+    pub(crate) fn storage_len(&self) -> usize {
+        self.heap.len()
+    }
+
+    fn discover(&mut self, depth: usize, sibling_idx: usize, node: TreeNode<'b, 'a, V, T>) {
+        self.heap.push(Scored {
+            score: (self.score)(node.value()),
+            order: Reverse(self.num_discovered),
+            depth,
+            sibling_idx,
+            node,
+        });
+        self.num_discovered += 1;
+    }
+}
+
+impl<'b, 'a, V, T, K, S> Iterator for BestFirst<'b, 'a, V, T, K, S>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+    K: Ord,
+    S: Fn(&T) -> K,
+{
+    type Item = (usize, usize, TreeNode<'b, 'a, V, T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Scored {
+            depth,
+            sibling_idx,
+            node,
+            ..
+        } = self.heap.pop()?;
+        for (i, child) in node.children().enumerate() {
+            self.discover(depth + 1, i, child);
+        }
+        Some((depth, sibling_idx, node))
+    }
+}
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Walks the subtree rooted at this node in best first order with respect to the `score`;
+    /// see [`BestFirst`].
+    pub fn walk_best_first<K, S>(&self, score: S) -> BestFirst<'b, 'a, V, T, K, S>
+    where
+        K: Ord,
+        S: Fn(&T) -> K,
+    {
+        BestFirst::new(*self, score)
+    }
+}
+
+struct Scored<K, N> {
+    score: K,
+    order: Reverse<usize>,
+    depth: usize,
+    sibling_idx: usize,
+    node: N,
+}
+
+impl<K: Ord, N> PartialEq for Scored<K, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<K: Ord, N> Eq for Scored<K, N> {}
+
+impl<K: Ord, N> PartialOrd for Scored<K, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord, N> Ord for Scored<K, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.score, self.order).cmp(&(&other.score, other.order))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn best_first_root_only() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        let items: Vec<_> = tree
+            .root()
+            .unwrap()
+            .walk_best_first(|x| *x)
+            .map(|(depth, sibling_idx, node)| (depth, sibling_idx, *node.value()))
+            .collect();
+        assert_eq!(items, [(0, 0, 'a')]);
+    }
+
+    #[test]
+    fn walk_best_first() {
+        let tree = sample();
+        let root = tree.root().unwrap();
+
+        let items: Vec<_> = root
+            .walk_best_first(|x| *x)
+            .map(|(depth, sibling_idx, x)| (depth, sibling_idx, *x.value()))
+            .collect();
+        let expected = [
+            (0, 0, 'a'),
+            (1, 1, 'c'),
+            (2, 0, 'f'),
+            (3, 0, 'g'),
+            (1, 0, 'b'),
+            (2, 1, 'e'),
+            (2, 0, 'd'),
+        ];
+        assert_eq!(items, expected);
+
+        // equal scores are visited in the order of discovery, which is breadth first
+        let values: String = root
+            .walk_best_first(|_| 0)
+            .map(|(_, _, x)| *x.value())
+            .collect();
+        assert_eq!(values, "abcdefg");
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{
+    traversal::depth_first::DepthFirst, tree_node::TreeNode, variants::tree_variant::TreeVariant,
+};
+
+/// Depth first walk which stops after yielding a maximum number of nodes; created by [`TreeNode::walk_bounded`].
+///
+/// Each item is a `(depth, sibling_idx, node)` tuple where the depth and sibling index of the root
+/// of the walk are both zero.
+pub struct BoundedWalk<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    walk: DepthFirst<'b, 'a, V, T>,
+    remaining: usize,
+}
+
+impl<'b, 'a, V, T> BoundedWalk<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Returns whether or not the walk stopped, or will stop, before visiting all nodes due to the budget.
+    ///
+    /// The result is meaningful once the iterator returned None.
+    pub fn is_truncated(&self) -> bool {
+        self.remaining == 0 && self.walk.storage_len() > 0
+    }
+
+    /// Returns the number of nodes of the subtree which are not yielded by the walk so far.
+    ///
+    /// Once the iterator returned None, this is the number of nodes left out due to the budget.
+    /// Time complexity is linear in the returned number.
+    pub fn num_unvisited(&self) -> usize {
+        self.walk.num_remaining()
+    }
+}
+
+impl<'b, 'a, V, T> Iterator for BoundedWalk<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    type Item = (usize, usize, TreeNode<'b, 'a, V, T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.remaining {
+            0 => None,
+            _ => {
+                let item = self.walk.next()?;
+                self.remaining -= 1;
+                Some(item)
+            }
+        }
+    }
+}
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Walks the subtree rooted at this node in depth first order yielding at most `max_nodes` nodes.
+    ///
+    /// Once the iterator is consumed, [`BoundedWalk::is_truncated`] and [`BoundedWalk::num_unvisited`] tell whether
+    /// and how many nodes were left out.
+    pub fn walk_bounded(&self, max_nodes: usize) -> BoundedWalk<'b, 'a, V, T> {
+        BoundedWalk {
+            walk: DepthFirst::new(*self),
+            remaining: max_nodes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn walk_bounded_root_only() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        let root = tree.root().unwrap();
+
+        let mut walk = root.walk_bounded(0);
+        assert!(walk.next().is_none());
+        assert!(walk.is_truncated());
+        assert_eq!(walk.num_unvisited(), 1);
+
+        let mut walk = root.walk_bounded(1);
+        assert_eq!(walk.next().map(|(_, _, node)| *node.value()), Some('a'));
+        assert!(walk.next().is_none());
+        assert!(!walk.is_truncated());
+        assert_eq!(walk.num_unvisited(), 0);
+    }
+
+    #[test]
+    fn walk_bounded_multi_level() {
+        let tree = sample();
+        let root = tree.root().unwrap();
+
+        let mut walk = root.walk_bounded(3);
+        let values: String = walk.by_ref().map(|(_, _, x)| *x.value()).collect();
+        assert_eq!(values, "abd");
+        // e and the subtree of c are still waiting in the stack
+        assert_eq!(walk.num_unvisited(), 4);
+        assert!(walk.is_truncated());
+
+        let mut walk = root.walk_bounded(7);
+        assert_eq!(walk.by_ref().count(), 7);
+        assert_eq!(walk.num_unvisited(), 0);
+        assert!(!walk.is_truncated());
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{
+    traversal::iterative_deepening::IterativeDeepening, tree_node::TreeNode,
+    variants::tree_variant::TreeVariant,
+};
+use std::collections::VecDeque;
+
+/// Breadth first walk whose queue never holds more than a maximum number of nodes; created by
+/// [`TreeNode::walk_bfs_bounded`].
+///
+/// Each item is a `(depth, node)` pair where the depth of the root of the walk is zero.
+///
+/// When the children of the next node do not fit in the queue, the walk drops the queue and continues with an
+/// [`IterativeDeepening`] walk from the position it has reached. Since both walks yield the nodes level by level
+/// and from left to right, the order of the nodes is not affected by the switch. Afterwards, memory is bounded
+/// by the depth of the tree rather than its width, at the cost of re-walking the upper levels for each level.
+pub struct BoundedBreadthFirst<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    root: TreeNode<'b, 'a, V, T>,
+    max_queue_len: usize,
+    queue: VecDeque<(usize, TreeNode<'b, 'a, V, T>)>,
+    depth: usize,
+    num_yielded_at_depth: usize,
+    deepening: Option<IterativeDeepening<'b, 'a, V, T>>,
+}
+
+impl<'b, 'a, V, T> BoundedBreadthFirst<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Returns whether or not the walk switched to iterative deepening since its queue would exceed the maximum
+    /// length.
+    pub fn is_deepening(&self) -> bool {
+        self.deepening.is_some()
+    }
+
+    fn switch_to_deepening(&mut self) {
+        let mut deepening =
+            IterativeDeepening::new(self.root, usize::MAX).starting_at_depth(self.depth);
+        for _ in 0..self.num_yielded_at_depth {
+            deepening.next();
+        }
+        self.queue = VecDeque::new();
+        self.deepening = Some(deepening);
+    }
+}
+
+impl<'b, 'a, V, T> Iterator for BoundedBreadthFirst<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    type Item = (usize, TreeNode<'b, 'a, V, T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(deepening) = &mut self.deepening {
+            let node = deepening.next()?;
+            return Some((deepening.depth_limit(), node));
+        }
+
+        let (depth, node) = self.queue.pop_front()?;
+        if depth != self.depth {
+            self.depth = depth;
+            self.num_yielded_at_depth = 0;
+        }
+        self.num_yielded_at_depth += 1;
+
+        match self.queue.len() + node.children().count() > self.max_queue_len {
+            true => self.switch_to_deepening(),
+            false => self
+                .queue
+                .extend(node.children().map(|child| (depth + 1, child))),
+        }
+        Some((depth, node))
+    }
+}
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Walks the subtree rooted at this node in breadth first order while keeping at most `max_queue_len` nodes
+    /// in the queue; see [`BoundedBreadthFirst`].
+    pub fn walk_bfs_bounded(&self, max_queue_len: usize) -> BoundedBreadthFirst<'b, 'a, V, T> {
+        BoundedBreadthFirst {
+            root: *self,
+            max_queue_len,
+            queue: VecDeque::from([(0, *self)]),
+            depth: 0,
+            num_yielded_at_depth: 0,
+            deepening: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn walk_bfs_bounded_root_only() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        let mut walk = tree.root().unwrap().walk_bfs_bounded(0);
+        assert_eq!(
+            walk.next().map(|(depth, x)| (depth, *x.value())),
+            Some((0, 'a'))
+        );
+        assert!(walk.next().is_none());
+        assert!(!walk.is_deepening());
+    }
+
+    #[test]
+    fn walk_bfs_bounded() {
+        let tree = sample();
+        let root = tree.root().unwrap();
+        let expected = [
+            (0, 'a'),
+            (1, 'b'),
+            (1, 'c'),
+            (2, 'd'),
+            (2, 'e'),
+            (2, 'f'),
+            (3, 'g'),
+        ];
+
+        for max_queue_len in 0..5 {
+            let mut walk = root.walk_bfs_bounded(max_queue_len);
+            let items: Vec<_> = walk.by_ref().map(|(d, x)| (d, *x.value())).collect();
+            assert_eq!(items, expected);
+            assert_eq!(walk.is_deepening(), max_queue_len < 3);
+        }
+
+        let mut walk = root.walk_bfs_bounded(2);
+        let items: Vec<_> = walk.by_ref().take(2).map(|(_, x)| *x.value()).collect();
+        assert_eq!(items, ['a', 'b']);
+        assert!(walk.is_deepening());
+        let c = tree.node_at(&[1]).unwrap();
+        let items: Vec<_> = c
+            .walk_bfs_bounded(0)
+            .map(|(d, x)| (d, *x.value()))
+            .collect();
+        assert_eq!(items, [(0, 'c'), (1, 'f'), (2, 'g')]);
+    }
+}
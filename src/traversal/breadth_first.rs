@@ -0,0 +1,264 @@
+use crate::{tree_node::TreeNode, variants::tree_variant::TreeVariant};
+use std::collections::VecDeque;
+
+/// Iterator over the nodes of a subtree in breadth first (level) order.
+///
+/// Each item is a `(depth, sibling_idx, node)` tuple where the depth and sibling index of the root
+/// of the walk are both zero.
+pub struct BreadthFirst<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    queue: VecDeque<(usize, usize, TreeNode<'b, 'a, V, T>)>,
+    right_to_left: bool,
+}
+
+impl<'b, 'a, V, T> BreadthFirst<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    pub(crate) fn new(root: TreeNode<'b, 'a, V, T>) -> Self {
+        Self {
+            queue: VecDeque::from([(0, 0, root)]),
+            right_to_left: false,
+        }
+    }
+
+    /// Transforms the iterator so that children of each node are visited from right to left;
+    /// sibling indices of the yielded nodes are still their positions from the left.
+    pub fn right_to_left(self) -> Self {
+        Self {
+            right_to_left: true,
+            ..self
+        }
+    }
+
+    /// Restarts the walk from the `root`, reusing the queue of the walk.
+    pub fn restart(&mut self, root: TreeNode<'b, 'a, V, T>) {
+        self.queue.clear();
+        self.queue.push_back((0, 0, root));
+    }
+
+    /// Number of nodes currently waiting in the queue of the walk.
+    pub fn storage_len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Shrinks the capacity of the queue of the walk to `capacity`, or to [`BreadthFirst::storage_len`] if it is
+    /// larger; nodes waiting in the queue are kept and the walk continues as before.
+    pub fn shrink_storage_to(&mut self, capacity: usize) {
+        self.queue.shrink_to(capacity);
+    }
+
+    /// Drops the nodes waiting in the queue and releases its memory; the walk ends until it is restarted.
+    pub fn clear_storage(&mut self) {
+        self.queue = VecDeque::new();
+    }
+}
+
+impl<'b, 'a, V, T> Iterator for BreadthFirst<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    type Item = (usize, usize, TreeNode<'b, 'a, V, T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (depth, sibling_idx, node) = self.queue.pop_front()?;
+        let len = self.queue.len();
+        let children = node.children().enumerate();
+        self.queue
+            .extend(children.map(|(i, child)| (depth + 1, i, child)));
+        if self.right_to_left {
+            let end = self.queue.len();
+            for i in 0..(end - len) / 2 {
+                self.queue.swap(len + i, end - 1 - i);
+            }
+        }
+        Some((depth, sibling_idx, node))
+    }
+}
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Walks the subtree rooted at this node in breadth first (level) order; see [`BreadthFirst`].
+    pub fn walk_bfs(&self) -> BreadthFirst<'b, 'a, V, T> {
+        BreadthFirst::new(*self)
+    }
+
+    /// Walks the subtree rooted at this node level by level, yielding one iterator per depth relative to this node
+    /// over the values at that depth from left to right.
+    ///
+    /// Whenever a level starts, the queue of the underlying [`BreadthFirst`] walk holds exactly the nodes of that
+    /// level; these are popped into a buffer of the level's width which is yielded.
+    pub fn walk_levels(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = &'b T> + use<'b, 'a, V, T>> + use<'b, 'a, V, T>
+    {
+        let mut walk = BreadthFirst::new(*self);
+        std::iter::from_fn(move || {
+            let width = walk.storage_len();
+            let level = walk.by_ref().take(width).map(|(_, _, node)| node.value());
+            (width > 0).then(|| level.collect::<Vec<_>>().into_iter())
+        })
+    }
+
+    /// Returns the values of the subtree rooted at this node grouped by depth relative to this node;
+    /// the i-th vector contains the values at depth i, from left to right.
+    pub fn group_by_depth(&self) -> Vec<Vec<&'b T>> {
+        self.group_nodes_by_depth()
+            .into_iter()
+            .map(|level| level.into_iter().map(|node| node.value()).collect())
+            .collect()
+    }
+
+    /// Returns the nodes of the subtree rooted at this node grouped by depth relative to this node;
+    /// the i-th vector contains the nodes at depth i, from left to right.
+    pub fn group_nodes_by_depth(&self) -> Vec<Vec<Self>> {
+        let mut levels: Vec<Vec<Self>> = vec![];
+        for (depth, _, node) in BreadthFirst::new(*self) {
+            if levels.len() == depth {
+                levels.push(vec![]);
+            }
+            levels[depth].push(node);
+        }
+        levels
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn breadth_first_root_only() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        let items: Vec<_> = BreadthFirst::new(tree.root().unwrap())
+            .map(|(depth, sibling_idx, node)| (depth, sibling_idx, *node.value()))
+            .collect();
+        assert_eq!(items, [(0, 0, 'a')]);
+    }
+
+    #[test]
+    fn walk_bfs() {
+        let tree = sample();
+        let root = tree.root().unwrap();
+
+        let values: String = root.walk_bfs().map(|(_, _, x)| *x.value()).collect();
+        assert_eq!(values, "abcdefg");
+
+        let items: Vec<_> = root
+            .walk_bfs()
+            .right_to_left()
+            .map(|(depth, sibling_idx, x)| (depth, sibling_idx, *x.value()))
+            .collect();
+        let expected = [
+            (0, 0, 'a'),
+            (1, 1, 'c'),
+            (1, 0, 'b'),
+            (2, 0, 'f'),
+            (2, 1, 'e'),
+            (2, 0, 'd'),
+            (3, 0, 'g'),
+        ];
+        assert_eq!(items, expected);
+    }
+
+    #[test]
+    fn bfs_storage() {
+        let tree = sample();
+        let mut walk = tree.root().unwrap().walk_bfs();
+        assert_eq!(walk.storage_len(), 1);
+
+        let values: String = walk.by_ref().take(2).map(|(_, _, x)| *x.value()).collect();
+        assert_eq!(values, "ab");
+        assert_eq!(walk.storage_len(), 3);
+
+        walk.shrink_storage_to(0);
+        assert!(walk.queue.capacity() >= 3);
+        let values: String = walk.by_ref().map(|(_, _, x)| *x.value()).collect();
+        assert_eq!(values, "cdefg");
+
+        walk.restart(tree.root().unwrap());
+        walk.next();
+        walk.clear_storage();
+        assert_eq!(walk.storage_len(), 0);
+        assert_eq!(walk.queue.capacity(), 0);
+        assert!(walk.next().is_none());
+
+        walk.restart(tree.root().unwrap());
+        assert_eq!(walk.count(), 7);
+    }
+
+    #[test]
+    fn walk_levels_root_only() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        let levels: Vec<Vec<_>> = tree
+            .root()
+            .unwrap()
+            .walk_levels()
+            .map(Iterator::collect)
+            .collect();
+        assert_eq!(levels, [[&'a']]);
+    }
+
+    #[test]
+    fn walk_levels_multi_level() {
+        let tree = sample();
+        let levels: Vec<String> = tree
+            .root()
+            .unwrap()
+            .walk_levels()
+            .map(|level| level.collect())
+            .collect();
+        assert_eq!(levels, ["a", "bc", "def", "g"]);
+
+        let levels: Vec<String> = tree
+            .node_at(&[1])
+            .unwrap()
+            .walk_levels()
+            .map(|level| level.collect())
+            .collect();
+        assert_eq!(levels, ["c", "f", "g"]);
+    }
+
+    #[test]
+    fn group_by_depth_root_only() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        let root = tree.root().unwrap();
+        assert_eq!(root.group_by_depth(), [[&'a']]);
+        assert_eq!(root.group_nodes_by_depth()[0][0].value(), &'a');
+    }
+
+    #[test]
+    fn group_by_depth_multi_level() {
+        let tree = sample();
+        let root = tree.root().unwrap();
+        let groups = root.group_by_depth();
+        assert_eq!(
+            groups,
+            [
+                vec![&'a'],
+                vec![&'b', &'c'],
+                vec![&'d', &'e', &'f'],
+                vec![&'g']
+            ]
+        );
+
+        let groups: Vec<String> = root
+            .group_nodes_by_depth()
+            .iter()
+            .map(|level| level.iter().map(|x| *x.value()).collect())
+            .collect();
+        assert_eq!(groups, ["a", "bc", "def", "g"]);
+
+        let b = tree.node_at(&[0]).unwrap();
+        assert_eq!(b.group_by_depth(), [vec![&'b'], vec![&'d', &'e']]);
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{
+    traversal::depth_first::DepthFirst, tree_node::TreeNode, variants::tree_variant::TreeVariant,
+};
+
+/// Decision taken after visiting a node in a [`CustomWalk`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WalkControl {
+    /// Continues the walk with the children of the visited node.
+    Descend,
+    /// Continues the walk skipping the subtree below the visited node.
+    Skip,
+    /// Terminates the walk.
+    Stop,
+}
+
+/// Depth first walk where a closure decides, for each visited node, whether to descend into its children,
+/// skip them or stop the walk; created by [`TreeNode::custom_walk`].
+///
+/// Each item is a `(depth, node)` pair where the depth of the root of the walk is zero.
+/// Every visited node is yielded, including the one for which the walk is stopped.
+pub struct CustomWalk<'b, 'a, V, T, F>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+    F: FnMut(usize, TreeNode<'b, 'a, V, T>) -> WalkControl,
+{
+    walk: DepthFirst<'b, 'a, V, T>,
+    control: F,
+}
+
+impl<'b, 'a, V, T, F> Iterator for CustomWalk<'b, 'a, V, T, F>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+    F: FnMut(usize, TreeNode<'b, 'a, V, T>) -> WalkControl,
+{
+    type Item = (usize, TreeNode<'b, 'a, V, T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // the walk pushes the children of the yielded node on top of the remaining stack
+        let len = self.walk.storage_len();
+        let (depth, _, node) = self.walk.next()?;
+        match (self.control)(depth, node) {
+            WalkControl::Descend => {}
+            WalkControl::Skip => self.walk.truncate_storage(len - 1),
+            WalkControl::Stop => self.walk.truncate_storage(0),
+        }
+        Some((depth, node))
+    }
+}
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Walks the subtree rooted at this node in depth first order where `control` is called with the depth
+    /// of each visited node and the node itself, and decides how the walk continues; see [`WalkControl`].
+    pub fn custom_walk<F>(&self, control: F) -> CustomWalk<'b, 'a, V, T, F>
+    where
+        F: FnMut(usize, TreeNode<'b, 'a, V, T>) -> WalkControl,
+    {
+        CustomWalk {
+            walk: DepthFirst::new(*self),
+            control,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn custom_walk_root_only() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        let root = tree.root().unwrap();
+        for control in [WalkControl::Descend, WalkControl::Skip, WalkControl::Stop] {
+            let mut calls = vec![];
+            let visited: Vec<_> = root
+                .custom_walk(|depth, node| {
+                    calls.push((depth, *node.value()));
+                    control
+                })
+                .map(|(depth, node)| (depth, *node.value()))
+                .collect();
+            assert_eq!(visited, [(0, 'a')]);
+            assert_eq!(calls, [(0, 'a')]);
+        }
+    }
+
+    #[test]
+    fn custom_walk_skip_and_stop() {
+        let tree = sample();
+        let root = tree.root().unwrap();
+        let walk = |at: char, control_at: WalkControl| {
+            let mut calls = String::new();
+            let visited: String = root
+                .custom_walk(|_, node| {
+                    calls.push(*node.value());
+                    match *node.value() == at {
+                        true => control_at,
+                        false => WalkControl::Descend,
+                    }
+                })
+                .map(|(_, node)| *node.value())
+                .collect();
+            (visited, calls)
+        };
+
+        assert_eq!(walk('b', WalkControl::Descend).0, "abdecfg");
+        // b is yielded, but skipping it omits d and e and continues with its sibling
+        assert_eq!(
+            walk('b', WalkControl::Skip),
+            ("abcfg".into(), "abcfg".into())
+        );
+        // stopping at c yields c and ends the walk
+        assert_eq!(
+            walk('c', WalkControl::Stop),
+            ("abdec".into(), "abdec".into())
+        );
+        assert_eq!(walk('b', WalkControl::Stop), ("ab".into(), "ab".into()));
+
+        let depths: Vec<_> = root
+            .custom_walk(|depth, _| match depth {
+                2 => WalkControl::Skip,
+                _ => WalkControl::Descend,
+            })
+            .map(|(depth, node)| (depth, *node.value()))
+            .collect();
+        assert_eq!(
+            depths,
+            [(0, 'a'), (1, 'b'), (2, 'd'), (2, 'e'), (1, 'c'), (2, 'f')]
+        );
+    }
+}
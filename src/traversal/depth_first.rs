@@ -0,0 +1,393 @@
+use crate::{tree_node::TreeNode, variants::tree_variant::TreeVariant};
+
+/// Iterator over the nodes of a subtree in depth first (pre-order) order.
+///
+/// Each item is a `(depth, sibling_idx, node)` tuple where the depth and sibling index of the root
+/// of the walk are both zero.
+pub struct DepthFirst<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    stack: Vec<(usize, usize, TreeNode<'b, 'a, V, T>)>,
+    right_to_left: bool,
+}
+
+impl<'b, 'a, V, T> DepthFirst<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    pub(crate) fn new(root: TreeNode<'b, 'a, V, T>) -> Self {
+        Self {
+            stack: vec![(0, 0, root)],
+            right_to_left: false,
+        }
+    }
+
+    /// Transforms the iterator so that children of each node are visited from right to left;
+    /// sibling indices of the yielded nodes are still their positions from the left.
+    pub fn right_to_left(self) -> Self {
+        Self {
+            right_to_left: true,
+            ..self
+        }
+    }
+
+    /// Restarts the walk from the `root`, reusing the stack of the walk.
+    pub fn restart(&mut self, root: TreeNode<'b, 'a, V, T>) {
+        self.stack.clear();
+        self.stack.push((0, 0, root));
+    }
+
+    /// Number of nodes currently waiting in the stack of the walk.
+    pub fn storage_len(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Shrinks the capacity of the stack of the walk to `capacity`, or to [`DepthFirst::storage_len`] if it is larger;
+    /// nodes waiting in the stack are kept and the walk continues as before.
+    pub fn shrink_storage_to(&mut self, capacity: usize) {
+        self.stack.shrink_to(capacity);
+    }
+
+    /// Drops the nodes waiting in the stack and releases its memory; the walk ends until it is restarted.
+    pub fn clear_storage(&mut self) {
+        self.stack = Vec::new();
+    }
+
+    /// Drops the nodes waiting in the stack beyond the first `len`; the most recently pushed ones are dropped first.
+    pub(crate) fn truncate_storage(&mut self, len: usize) {
+        self.stack.truncate(len);
+    }
+
+    /// Number of nodes which are not yet yielded by the walk; linear in this number.
+    pub(crate) fn num_remaining(&self) -> usize {
+        let subtrees = self.stack.iter().map(|(_, _, node)| Self::new(*node));
+        subtrees.map(|subtree| subtree.count()).sum()
+    }
+}
+
+impl<'b, 'a, V, T> Iterator for DepthFirst<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    type Item = (usize, usize, TreeNode<'b, 'a, V, T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (depth, sibling_idx, node) = self.stack.pop()?;
+        let len = self.stack.len();
+        let children = node.children().enumerate();
+        self.stack
+            .extend(children.map(|(i, child)| (depth + 1, i, child)));
+        if !self.right_to_left {
+            self.stack[len..].reverse();
+        }
+        Some((depth, sibling_idx, node))
+    }
+}
+
+/// Iterator over the nodes of subtrees rooted at each of the `roots` in depth first order, one subtree after
+/// the other, sharing a single stack.
+///
+/// Each item is a `(depth, sibling_idx, node)` tuple relative to the root of the subtree being walked.
+pub struct DepthFirstMany<'b, 'a, V, T, I>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+    I: Iterator<Item = TreeNode<'b, 'a, V, T>>,
+{
+    roots: I,
+    depth_first: DepthFirst<'b, 'a, V, T>,
+}
+
+impl<'b, 'a, V, T, I> Iterator for DepthFirstMany<'b, 'a, V, T, I>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+    I: Iterator<Item = TreeNode<'b, 'a, V, T>>,
+{
+    type Item = (usize, usize, TreeNode<'b, 'a, V, T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.depth_first.next() {
+                Some(x) => return Some(x),
+                None => {
+                    let root = self.roots.next()?;
+                    self.depth_first.stack.push((0, 0, root));
+                }
+            }
+        }
+    }
+}
+
+/// Walks the subtrees rooted at each of the `roots` in depth first order as a single iteration;
+/// see [`DepthFirstMany`].
+pub fn walk_many_dfs<'b, 'a, V, T, I>(roots: I) -> DepthFirstMany<'b, 'a, V, T, I::IntoIter>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+    I: IntoIterator<Item = TreeNode<'b, 'a, V, T>>,
+{
+    DepthFirstMany {
+        roots: roots.into_iter(),
+        depth_first: DepthFirst {
+            stack: vec![],
+            right_to_left: false,
+        },
+    }
+}
+
+/// Walks the subtree rooted at `root` in depth first order yielding each node together with the position of its
+/// parent, where the position of a node is its index in the walk; the parent of `root` is None.
+pub(crate) fn walk_with_parent_positions<'b, 'a, V, T>(
+    root: TreeNode<'b, 'a, V, T>,
+) -> impl Iterator<Item = (Option<usize>, TreeNode<'b, 'a, V, T>)>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    let mut last_at_depth: Vec<usize> = vec![];
+    DepthFirst::new(root)
+        .enumerate()
+        .map(move |(position, (depth, _, node))| {
+            last_at_depth.truncate(depth);
+            let parent = last_at_depth.last().copied();
+            last_at_depth.push(position);
+            (parent, node)
+        })
+}
+
+/// Collects the nodes of the subtree rooted at `root` in depth first order together with the size of the
+/// subtree rooted at each node.
+///
+/// The node at position `p` has its first child at position `p + 1` and its next sibling at position `p + sizes[p]`.
+pub(crate) fn nodes_with_subtree_sizes<'b, 'a, V, T>(
+    root: TreeNode<'b, 'a, V, T>,
+) -> (Vec<TreeNode<'b, 'a, V, T>>, Vec<usize>)
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    let (parents, nodes): (Vec<_>, Vec<_>) = walk_with_parent_positions(root).unzip();
+
+    let mut sizes = vec![1; nodes.len()];
+    for (position, parent) in parents.iter().enumerate().rev() {
+        if let Some(parent) = *parent {
+            sizes[parent] += sizes[position];
+        }
+    }
+    (nodes, sizes)
+}
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Walks the subtree rooted at this node in depth first (pre-order) order; see [`DepthFirst`].
+    pub fn walk_dfs(&self) -> DepthFirst<'b, 'a, V, T> {
+        DepthFirst::new(*self)
+    }
+
+    /// Walks the subtree rooted at this node in depth first order yielding only the structure;
+    /// i.e., `(depth, sibling_idx)` of each node.
+    pub fn walk_structure(&self) -> impl Iterator<Item = (usize, usize)> + use<'b, 'a, V, T> {
+        DepthFirst::new(*self).map(|(depth, sibling_idx, _)| (depth, sibling_idx))
+    }
+
+    /// Walks the subtree rooted at this node in depth first order yielding the size of the subtree rooted at each
+    /// node together with the node.
+    ///
+    /// Sizes are computed by a first pass over the subtree before the walk starts.
+    pub fn walk_with_subtree_sizes(&self) -> impl Iterator<Item = (usize, Self)> {
+        let (nodes, sizes) = nodes_with_subtree_sizes(*self);
+        sizes.into_iter().zip(nodes)
+    }
+
+    /// Returns the `k`-th node of the subtree rooted at this node in depth first order, where this node is the
+    /// zeroth; None if the subtree has `k` or fewer nodes.
+    ///
+    /// Visits at most `k + 1` nodes.
+    pub fn nth_in_dfs(&self, k: usize) -> Option<Self> {
+        DepthFirst::new(*self).nth(k).map(|(_, _, node)| node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn walk_dfs() {
+        let tree = sample();
+        let root = tree.root().unwrap();
+
+        let values: String = root.walk_dfs().map(|(_, _, x)| *x.value()).collect();
+        assert_eq!(values, "abdecfg");
+
+        let items: Vec<_> = root
+            .walk_dfs()
+            .right_to_left()
+            .map(|(depth, sibling_idx, x)| (depth, sibling_idx, *x.value()))
+            .collect();
+        let expected = [
+            (0, 0, 'a'),
+            (1, 1, 'c'),
+            (2, 0, 'f'),
+            (3, 0, 'g'),
+            (1, 0, 'b'),
+            (2, 1, 'e'),
+            (2, 0, 'd'),
+        ];
+        assert_eq!(items, expected);
+    }
+
+    #[test]
+    fn dfs_storage() {
+        let tree = sample();
+        let mut walk = tree.root().unwrap().walk_dfs();
+        assert_eq!(walk.storage_len(), 1);
+
+        let values: String = walk.by_ref().take(2).map(|(_, _, x)| *x.value()).collect();
+        assert_eq!(values, "ab");
+        assert_eq!(walk.storage_len(), 3);
+
+        walk.shrink_storage_to(0);
+        assert_eq!(walk.stack.capacity(), 3);
+        let values: String = walk.by_ref().map(|(_, _, x)| *x.value()).collect();
+        assert_eq!(values, "decfg");
+
+        walk.restart(tree.root().unwrap());
+        walk.next();
+        walk.clear_storage();
+        assert_eq!(walk.storage_len(), 0);
+        assert_eq!(walk.stack.capacity(), 0);
+        assert!(walk.next().is_none());
+
+        walk.restart(tree.root().unwrap());
+        assert_eq!(walk.count(), 7);
+    }
+
+    #[test]
+    fn walk_structure_root_only() {
+        let tree: Tree<AnyAry, ()> = Tree::with_root(());
+        let structure: Vec<_> = tree.root().unwrap().walk_structure().collect();
+        assert_eq!(structure, [(0, 0)]);
+    }
+
+    #[test]
+    fn walk_structure_multi_level() {
+        let tree = sample();
+        let structure: Vec<_> = tree.root().unwrap().walk_structure().collect();
+        let expected = [(0, 0), (1, 0), (2, 0), (2, 1), (1, 1), (2, 0), (3, 0)];
+        assert_eq!(structure, expected);
+
+        let structure: Vec<_> = tree.node_at(&[1]).unwrap().walk_structure().collect();
+        assert_eq!(structure, [(0, 0), (1, 0), (2, 0)]);
+    }
+
+    #[test]
+    fn walk_with_subtree_sizes_root_only() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        let root = tree.root().unwrap();
+        let sizes: Vec<_> = root
+            .walk_with_subtree_sizes()
+            .map(|(size, node)| (size, *node.value()))
+            .collect();
+        assert_eq!(sizes, [(1, 'a')]);
+    }
+
+    #[test]
+    fn walk_with_subtree_sizes_multi_level() {
+        let tree = sample();
+        let items: Vec<_> = tree
+            .root()
+            .unwrap()
+            .walk_with_subtree_sizes()
+            .map(|(size, node)| (size, *node.value()))
+            .collect();
+        let expected = [
+            (7, 'a'),
+            (3, 'b'),
+            (1, 'd'),
+            (1, 'e'),
+            (3, 'c'),
+            (2, 'f'),
+            (1, 'g'),
+        ];
+        assert_eq!(items, expected);
+
+        let sizes: Vec<_> = tree
+            .node_at(&[0])
+            .unwrap()
+            .walk_with_subtree_sizes()
+            .map(|(size, _)| size)
+            .collect();
+        assert_eq!(sizes, [3, 1, 1]);
+    }
+
+    #[test]
+    fn nth_in_dfs_root_only() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        let root = tree.root().unwrap();
+        assert_eq!(root.nth_in_dfs(0).map(|x| *x.value()), Some('a'));
+        assert!(root.nth_in_dfs(1).is_none());
+    }
+
+    #[test]
+    fn nth_in_dfs_multi_level() {
+        let tree = sample();
+        let root = tree.root().unwrap();
+        let values: String = (0..7)
+            .map(|k| *root.nth_in_dfs(k).unwrap().value())
+            .collect();
+        assert_eq!(values, "abdecfg");
+        assert_eq!(root.nth_in_dfs(4).map(|x| *x.value()), Some('c'));
+        assert!(root.nth_in_dfs(7).is_none());
+
+        let c = tree.node_at(&[1]).unwrap();
+        assert_eq!(c.nth_in_dfs(2).map(|x| *x.value()), Some('g'));
+        assert!(c.nth_in_dfs(3).is_none());
+    }
+
+    #[test]
+    fn walk_many_dfs_roots() {
+        let a: Tree<AnyAry, _> = Tree::with_root('a');
+        let b: Tree<AnyAry, _> = Tree::with_root('b');
+        let roots = [a.root().unwrap(), b.root().unwrap(), a.root().unwrap()];
+
+        let values: Vec<_> = walk_many_dfs(roots)
+            .map(|(depth, _, node)| (depth, *node.value()))
+            .collect();
+        assert_eq!(values, [(0, 'a'), (0, 'b'), (0, 'a')]);
+
+        assert_eq!(
+            walk_many_dfs(Vec::<TreeNode<AnyAry, char>>::new()).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn walk_many_dfs_subtrees() {
+        let tree = sample();
+        let b = tree.node_at(&[0]).unwrap();
+        let c = tree.node_at(&[1]).unwrap();
+
+        let items: Vec<_> = walk_many_dfs([b, c])
+            .map(|(depth, sibling_idx, node)| (depth, sibling_idx, *node.value()))
+            .collect();
+        let expected = [
+            (0, 0, 'b'),
+            (1, 0, 'd'),
+            (1, 1, 'e'),
+            (0, 0, 'c'),
+            (1, 0, 'f'),
+            (2, 0, 'g'),
+        ];
+        assert_eq!(items, expected);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{tree_node::TreeNode, variants::tree_variant::TreeVariant};
+
+/// Depth first walk which prunes the subtrees of the nodes not satisfying a predicate; created by
+/// [`TreeNode::walk_filtered`].
+///
+/// Each item is a `(depth, sibling_idx, node)` tuple where the depth and sibling index of the root
+/// of the walk are both zero.
+pub struct FilteredWalk<'b, 'a, V, T, P>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+    P: FnMut(&TreeNode<'b, 'a, V, T>) -> bool,
+{
+    stack: Vec<(usize, usize, TreeNode<'b, 'a, V, T>)>,
+    predicate: P,
+}
+
+impl<'b, 'a, V, T, P> Iterator for FilteredWalk<'b, 'a, V, T, P>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+    P: FnMut(&TreeNode<'b, 'a, V, T>) -> bool,
+{
+    type Item = (usize, usize, TreeNode<'b, 'a, V, T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (depth, sibling_idx, node) = self.stack.pop()?;
+        let len = self.stack.len();
+        for (i, child) in node.children().enumerate() {
+            if (self.predicate)(&child) {
+                self.stack.push((depth + 1, i, child));
+            }
+        }
+        self.stack[len..].reverse();
+        Some((depth, sibling_idx, node))
+    }
+}
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Walks the subtree rooted at this node in depth first order, skipping every node for which `predicate` returns
+    /// false together with its entire subtree; the predicate is not called for the nodes of skipped subtrees.
+    ///
+    /// Sibling indices of the yielded nodes are their positions among all siblings, including the skipped ones.
+    pub fn walk_filtered<P>(&self, mut predicate: P) -> FilteredWalk<'b, 'a, V, T, P>
+    where
+        P: FnMut(&TreeNode<'b, 'a, V, T>) -> bool,
+    {
+        let stack = match predicate(self) {
+            true => vec![(0, 0, *self)],
+            false => vec![],
+        };
+        FilteredWalk { stack, predicate }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn walk_filtered_root_only() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        let root = tree.root().unwrap();
+        assert_eq!(root.walk_filtered(|_| true).count(), 1);
+        assert_eq!(root.walk_filtered(|_| false).count(), 0);
+    }
+
+    #[test]
+    fn walk_filtered_prunes_subtrees() {
+        let tree = sample();
+        let root = tree.root().unwrap();
+
+        // rejecting c skips f and g without calling the predicate on them
+        let mut called = String::new();
+        let items: Vec<_> = root
+            .walk_filtered(|x| {
+                called.push(*x.value());
+                *x.value() != 'c'
+            })
+            .map(|(depth, sibling_idx, x)| (depth, sibling_idx, *x.value()))
+            .collect();
+        assert_eq!(items, [(0, 0, 'a'), (1, 0, 'b'), (2, 0, 'd'), (2, 1, 'e')]);
+        assert_eq!(called, "abcde");
+
+        // sibling indices count the skipped siblings
+        let items: Vec<_> = root
+            .walk_filtered(|x| !matches!(x.value(), 'b' | 'd'))
+            .map(|(depth, sibling_idx, x)| (depth, sibling_idx, *x.value()))
+            .collect();
+        assert_eq!(items, [(0, 0, 'a'), (1, 1, 'c'), (2, 0, 'f'), (3, 0, 'g')]);
+
+        let b = tree.node_at(&[0]).unwrap();
+        let items: Vec<_> = b
+            .walk_filtered(|x| *x.value() != 'd')
+            .map(|(depth, sibling_idx, x)| (depth, sibling_idx, *x.value()))
+            .collect();
+        assert_eq!(items, [(0, 0, 'b'), (1, 1, 'e')]);
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{tree_node::TreeNode, variants::tree_variant::TreeVariant};
+
+/// Depth first walk yielding the information required to draw tree guides; created by
+/// [`TreeNode::walk_with_guides`].
+///
+/// Each item is a `(depth, is_last_sibling, value)` tuple where `is_last_sibling` tells whether the node is the last
+/// child of its parent; it is true for the root of the walk which has depth zero. The flags of the ancestors of a
+/// node are those of the most recently yielded nodes at each smaller depth; [`TreeNode::visit_with_guides`] keeps
+/// track of them.
+pub struct WalkWithGuides<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    stack: Vec<(usize, bool, TreeNode<'b, 'a, V, T>)>,
+}
+
+impl<'b, 'a, V, T> Iterator for WalkWithGuides<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    type Item = (usize, bool, &'b T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (depth, is_last, node) = self.stack.pop()?;
+
+        let len = self.stack.len();
+        self.stack
+            .extend(node.children().map(|child| (depth + 1, false, child)));
+        if let Some(last) = self.stack[len..].last_mut() {
+            last.1 = true;
+        }
+        self.stack[len..].reverse();
+
+        Some((depth, is_last, node.value()))
+    }
+}
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Walks the subtree rooted at this node in depth first order yielding, together with the depth and value of
+    /// each node, whether the node is the last child of its parent; see [`WalkWithGuides`].
+    ///
+    /// This is the information needed to draw guides such as `│`, `├──` and `└──` while rendering the tree.
+    pub fn walk_with_guides(&self) -> WalkWithGuides<'b, 'a, V, T> {
+        WalkWithGuides {
+            stack: vec![(0, true, *self)],
+        }
+    }
+
+    /// Visits the subtree rooted at this node in depth first order, calling `visit` with the depth, the
+    /// `is_last_sibling_chain` and the value of each node.
+    ///
+    /// The chain has `depth` elements: its i-th element tells whether the ancestor at depth `i + 1` is the last child
+    /// of its parent, the last element referring to the node itself. The chain is a single buffer updated in place
+    /// as the walk proceeds.
+    pub fn visit_with_guides<F>(&self, mut visit: F)
+    where
+        F: FnMut(usize, &[bool], &'b T),
+    {
+        let mut is_last_sibling_chain = vec![];
+        for (depth, is_last, value) in self.walk_with_guides() {
+            if depth > 0 {
+                is_last_sibling_chain.truncate(depth - 1);
+                is_last_sibling_chain.push(is_last);
+            }
+            visit(depth, &is_last_sibling_chain, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn walk_with_guides_root_only() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        let items: Vec<_> = tree.root().unwrap().walk_with_guides().collect();
+        assert_eq!(items, [(0, true, &'a')]);
+    }
+
+    #[test]
+    fn walk_with_guides() {
+        let tree = sample();
+        let items: Vec<_> = tree.root().unwrap().walk_with_guides().collect();
+        let expected = [
+            (0, true, &'a'),
+            (1, false, &'b'),
+            (2, false, &'d'),
+            (2, true, &'e'),
+            (1, true, &'c'),
+            (2, true, &'f'),
+            (3, true, &'g'),
+        ];
+        assert_eq!(items, expected);
+    }
+
+    #[test]
+    fn visit_with_guides() {
+        let tree = sample();
+        let mut items = vec![];
+        tree.root()
+            .unwrap()
+            .visit_with_guides(|depth, chain, value| items.push((depth, chain.to_vec(), *value)));
+        let expected = [
+            (0, vec![], 'a'),
+            (1, vec![false], 'b'),
+            (2, vec![false, false], 'd'),
+            (2, vec![false, true], 'e'),
+            (1, vec![true], 'c'),
+            (2, vec![true, true], 'f'),
+            (3, vec![true, true, true], 'g'),
+        ];
+        assert_eq!(items, expected);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{tree_node::TreeNode, variants::dary::Binary};
+
+/// Iterator over the nodes of a binary subtree in in-order (left, node, right); created by
+/// [`TreeNode::walk_in_order`].
+///
+/// Each item is a `(depth, sibling_idx, node)` tuple where the depth and sibling index of the root
+/// of the walk are both zero, and the sibling index of a left child is 0 while that of a right child is 1.
+pub struct InOrder<'b, 'a, T>
+where
+    T: 'a,
+{
+    stack: Vec<(usize, usize, TreeNode<'b, 'a, Binary, T>)>,
+    current: Option<(usize, usize, TreeNode<'b, 'a, Binary, T>)>,
+}
+
+impl<'b, 'a, T> Iterator for InOrder<'b, 'a, T>
+where
+    T: 'a,
+{
+    type Item = (usize, usize, TreeNode<'b, 'a, Binary, T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((depth, sibling_idx, node)) = self.current.take() {
+            self.stack.push((depth, sibling_idx, node));
+            let [left, _] = node.children_array();
+            self.current = left.map(|left| (depth + 1, 0, left));
+        }
+
+        let (depth, sibling_idx, node) = self.stack.pop()?;
+        let [_, right] = node.children_array();
+        self.current = right.map(|right| (depth + 1, 1, right));
+        Some((depth, sibling_idx, node))
+    }
+}
+
+impl<'b, 'a, T> TreeNode<'b, 'a, Binary, T>
+where
+    T: 'a,
+{
+    /// Walks the subtree rooted at this node in in-order: the left subtree, then the node itself and finally the
+    /// right subtree; see [`InOrder`].
+    ///
+    /// Children are identified by their positions; a node with only a right child has no left subtree.
+    pub fn walk_in_order(&self) -> InOrder<'b, 'a, T> {
+        InOrder {
+            stack: vec![],
+            current: Some((0, 0, *self)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tree::Tree, variants::dary::Binary};
+
+    #[test]
+    fn in_order_root_only() {
+        let tree: Tree<Binary, _> = Tree::with_root('a');
+        let items: Vec<_> = tree
+            .root()
+            .unwrap()
+            .walk_in_order()
+            .map(|(depth, sibling_idx, node)| (depth, sibling_idx, *node.value()))
+            .collect();
+        assert_eq!(items, [(0, 0, 'a')]);
+    }
+
+    fn in_order(tree: &Tree<Binary, char>) -> Vec<(usize, usize, char)> {
+        let root = tree.root().unwrap();
+        let items = root.walk_in_order();
+        items
+            .map(|(depth, sibling_idx, node)| (depth, sibling_idx, *node.value()))
+            .collect()
+    }
+
+    #[test]
+    fn in_order_left_only() {
+        let mut tree: Tree<Binary, _> = Tree::with_root('c');
+        tree.set_child_at(&[], 0, 'b');
+        tree.set_child_at(&[0], 0, 'a');
+        assert_eq!(in_order(&tree), [(2, 0, 'a'), (1, 0, 'b'), (0, 0, 'c')]);
+    }
+
+    #[test]
+    fn in_order_right_only() {
+        let mut tree: Tree<Binary, _> = Tree::with_root('a');
+        tree.set_child_at(&[], 1, 'b');
+        tree.set_child_at(&[1], 1, 'c');
+        assert_eq!(in_order(&tree), [(0, 0, 'a'), (1, 1, 'b'), (2, 1, 'c')]);
+    }
+
+    #[test]
+    fn in_order_full() {
+        //    d
+        //  b   f
+        // a c e g
+        let mut tree: Tree<Binary, _> = Tree::with_root('d');
+        tree.set_child_at(&[], 0, 'b');
+        tree.set_child_at(&[], 1, 'f');
+        tree.set_child_at(&[0], 0, 'a');
+        tree.set_child_at(&[0], 1, 'c');
+        tree.set_child_at(&[1], 0, 'e');
+        tree.set_child_at(&[1], 1, 'g');
+        let expected = [
+            (2, 0, 'a'),
+            (1, 0, 'b'),
+            (2, 1, 'c'),
+            (0, 0, 'd'),
+            (2, 0, 'e'),
+            (1, 1, 'f'),
+            (2, 1, 'g'),
+        ];
+        assert_eq!(in_order(&tree), expected);
+
+        let f = tree.node_at(&[1]).unwrap();
+        let values: String = f.walk_in_order().map(|(_, _, x)| *x.value()).collect();
+        assert_eq!(values, "efg");
+    }
+
+    #[test]
+    fn in_order_mixed() {
+        //  b
+        // a   e
+        //   d
+        //  c
+        let mut tree: Tree<Binary, _> = Tree::with_root('b');
+        tree.set_child_at(&[], 0, 'a');
+        tree.set_child_at(&[], 1, 'e');
+        tree.set_child_at(&[1], 0, 'd');
+        tree.set_child_at(&[1, 0], 0, 'c');
+        let values: String = in_order(&tree).into_iter().map(|(_, _, x)| x).collect();
+        assert_eq!(values, "abcde");
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{tree_node::TreeNode, variants::tree_variant::TreeVariant};
+
+/// Iterator over the nodes of a subtree in iterative deepening depth first search (IDDFS) order.
+///
+/// The `d`-th pass is a depth-limited depth first search which yields the nodes at depth `d`.
+/// Passes continue until `max_depth` is reached or no node deeper than the current limit exists.
+/// Therefore, each node is yielded once, level by level, while the memory is bounded by the depth
+/// of the search rather than the width of the tree.
+pub struct IterativeDeepening<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    root: TreeNode<'b, 'a, V, T>,
+    max_depth: usize,
+    depth_limit: usize,
+    has_deeper: bool,
+    right_to_left: bool,
+    stack: Vec<(usize, TreeNode<'b, 'a, V, T>)>,
+}
+
+impl<'b, 'a, V, T> IterativeDeepening<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    pub(crate) fn new(root: TreeNode<'b, 'a, V, T>, max_depth: usize) -> Self {
+        Self {
+            root,
+            max_depth,
+            depth_limit: 0,
+            has_deeper: false,
+            right_to_left: false,
+            stack: vec![(0, root)],
+        }
+    }
+
+    /// Transforms the iterator so that children of each node are visited from right to left.
+    pub fn right_to_left(self) -> Self {
+        Self {
+            right_to_left: true,
+            ..self
+        }
+    }
+
+    /// Starts the walk with the pass at `depth`, skipping the nodes above it.
+    pub(crate) fn starting_at_depth(self, depth: usize) -> Self {
+        Self {
+            depth_limit: depth,
+            ..self
+        }
+    }
+
+    /// Depth limit of the current depth-limited pass.
+    pub fn depth_limit(&self) -> usize {
+        self.depth_limit
+    }
+}
+
+impl<'b, 'a, V, T> Iterator for IterativeDeepening<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    type Item = TreeNode<'b, 'a, V, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.stack.pop() {
+                Some((depth, node)) if depth == self.depth_limit => {
+                    self.has_deeper |= node.children().next().is_some();
+                    return Some(node);
+                }
+                Some((depth, node)) => {
+                    let len = self.stack.len();
+                    self.stack
+                        .extend(node.children().map(|child| (depth + 1, child)));
+                    if !self.right_to_left {
+                        self.stack[len..].reverse();
+                    }
+                }
+                None => match self.has_deeper && self.depth_limit < self.max_depth {
+                    true => {
+                        self.depth_limit += 1;
+                        self.has_deeper = false;
+                        self.stack.push((0, self.root));
+                    }
+                    false => return None,
+                },
+            }
+        }
+    }
+}
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Walks the subtree rooted at this node in iterative deepening depth first order, down to nodes at
+    /// `max_depth` relative to this node; see [`IterativeDeepening`].
+    ///
+    /// Each pass restarts from this node; hence, nodes above the current depth limit are revisited, though not
+    /// yielded, in every pass.
+    pub fn walk_iddfs(&self, max_depth: usize) -> IterativeDeepening<'b, 'a, V, T> {
+        IterativeDeepening::new(*self, max_depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::dary::Binary};
+
+    #[test]
+    fn walk_iddfs_root_only() {
+        let tree: Tree<Binary, _> = Tree::with_root(42);
+        let root = tree.root().unwrap();
+
+        let values: Vec<_> = root.walk_iddfs(0).map(|x| *x.value()).collect();
+        assert_eq!(values, [42]);
+
+        let values: Vec<_> = root.walk_iddfs(10).map(|x| *x.value()).collect();
+        assert_eq!(values, [42]);
+    }
+
+    #[test]
+    fn walk_iddfs_right_to_left_root_only() {
+        let tree: Tree<Binary, _> = Tree::with_root(42);
+        let root = tree.root().unwrap();
+
+        let iter = root.walk_iddfs(10).right_to_left();
+        assert_eq!(iter.map(|x| *x.value()).collect::<Vec<_>>(), [42]);
+    }
+
+    #[test]
+    fn walk_iddfs_right_to_left() {
+        let tree = sample();
+        let root = tree.root().unwrap();
+
+        let values: String = root.walk_iddfs(10).map(|x| *x.value()).collect();
+        assert_eq!(values, "abcdefg");
+
+        let values: String = root
+            .walk_iddfs(10)
+            .right_to_left()
+            .map(|x| *x.value())
+            .collect();
+        assert_eq!(values, "acbfedg");
+    }
+
+    #[test]
+    fn walk_iddfs_depth_three() {
+        let tree = sample();
+        let root = tree.root().unwrap();
+
+        // g is the only node at depth 3
+        let values: String = root.walk_iddfs(2).map(|x| *x.value()).collect();
+        assert_eq!(values, "abcdef");
+
+        // each pass revisits the nodes above its depth limit from the root to reach the nodes at the limit,
+        // such that d, e and f are reached through both b and c in the same pass
+        let mut walk = root.walk_iddfs(3);
+        let mut items = vec![];
+        while let Some(node) = walk.next() {
+            items.push((walk.depth_limit(), *node.value()));
+        }
+        let expected = [
+            (0, 'a'),
+            (1, 'b'),
+            (1, 'c'),
+            (2, 'd'),
+            (2, 'e'),
+            (2, 'f'),
+            (3, 'g'),
+        ];
+        assert_eq!(items, expected);
+        assert_eq!(walk.depth_limit(), 3);
+
+        // passes restart from the root of the walk rather than the root of the tree
+        let c = tree.node_at(&[1]).unwrap();
+        let values: String = c.walk_iddfs(3).map(|x| *x.value()).collect();
+        assert_eq!(values, "cfg");
+        let values: String = c.walk_iddfs(1).map(|x| *x.value()).collect();
+        assert_eq!(values, "cf");
+    }
+}
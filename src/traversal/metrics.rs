@@ -0,0 +1,104 @@
+use crate::{
+    queries::search::SearchOrder,
+    traversal::{best_first::BestFirst, breadth_first::BreadthFirst, depth_first::DepthFirst},
+    tree_node::TreeNode,
+    variants::tree_variant::TreeVariant,
+};
+use std::time::{Duration, Instant};
+
+/// Statistics of a walk, available with the `metrics` feature.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WalkStats {
+    /// Number of nodes visited by the walk.
+    pub num_visited: usize,
+    /// Maximum number of nodes waiting in the stack or queue of the walk at any point.
+    pub max_storage_len: usize,
+    /// Total time spent by the walk, including the time spent in the visitor.
+    pub duration: Duration,
+}
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Walks the subtree rooted at this node in the given `order`, calls `visit` on each node and returns
+    /// statistics of the walk.
+    pub fn walk_with_stats<F>(&self, order: SearchOrder<'_, T>, mut visit: F) -> WalkStats
+    where
+        F: FnMut(TreeNode<'b, 'a, V, T>),
+    {
+        let start = Instant::now();
+        let mut stats = WalkStats {
+            max_storage_len: 1,
+            ..Default::default()
+        };
+        let mut on_next = |node: TreeNode<'b, 'a, V, T>, storage_len: usize| {
+            stats.num_visited += 1;
+            stats.max_storage_len = stats.max_storage_len.max(storage_len);
+            visit(node);
+        };
+
+        match order {
+            SearchOrder::DepthFirst => {
+                let mut walk = DepthFirst::new(*self);
+                while let Some((_, _, node)) = walk.next() {
+                    on_next(node, walk.storage_len());
+                }
+            }
+            SearchOrder::BreadthFirst => {
+                let mut walk = BreadthFirst::new(*self);
+                while let Some((_, _, node)) = walk.next() {
+                    on_next(node, walk.storage_len());
+                }
+            }
+            SearchOrder::BestFirst(score) => {
+                let mut walk = BestFirst::new(*self, score);
+                while let Some((_, _, node)) = walk.next() {
+                    on_next(node, walk.storage_len());
+                }
+            }
+        }
+
+        stats.duration = start.elapsed();
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn walk_with_stats_root_only() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        let root = tree.root().unwrap();
+        let score = |x: &char| *x as i64;
+        let orders = [
+            SearchOrder::DepthFirst,
+            SearchOrder::BreadthFirst,
+            SearchOrder::BestFirst(&score),
+        ];
+        for order in orders {
+            let mut values = vec![];
+            let stats = root.walk_with_stats(order, |x| values.push(*x.value()));
+            assert_eq!(values, ['a']);
+            assert_eq!(stats.num_visited, 1);
+            assert_eq!(stats.max_storage_len, 1);
+        }
+    }
+
+    #[test]
+    fn walk_with_stats_best_first() {
+        let tree = sample();
+        let root = tree.root().unwrap();
+        let score = |x: &char| *x as i64;
+        let mut values = String::new();
+        let stats =
+            root.walk_with_stats(SearchOrder::BestFirst(&score), |x| values.push(*x.value()));
+        assert_eq!(values, "acfgbed");
+        assert_eq!(stats.num_visited, 7);
+        assert_eq!(stats.max_storage_len, 2);
+    }
+}
@@ -0,0 +1,15 @@
+pub mod best_first;
+pub mod bounded;
+pub mod bounded_breadth_first;
+pub mod breadth_first;
+pub mod custom_walk;
+pub mod depth_first;
+pub mod filtered;
+pub mod guides;
+pub mod in_order;
+pub mod iterative_deepening;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod post_order_groups;
+pub mod reverse;
+pub mod visit_item;
@@ -0,0 +1,70 @@
+use crate::{
+    traversal::depth_first::walk_with_parent_positions, tree_node::TreeNode,
+    variants::tree_variant::TreeVariant,
+};
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Returns groups of the nodes of the subtree rooted at this node such that all children of a node
+    /// belong to earlier groups than the node itself.
+    ///
+    /// The `h`-th group contains the nodes with height `h`; the first group holds the leaves and the last
+    /// group holds only this node. Nodes within a group do not depend on each other and can be processed
+    /// in parallel. Within each group, nodes are in depth first order.
+    pub fn post_order_groups(&self) -> impl Iterator<Item = Vec<TreeNode<'b, 'a, V, T>>> {
+        let (parents, nodes): (Vec<_>, Vec<_>) = walk_with_parent_positions(*self).unzip();
+
+        let mut heights = vec![0; nodes.len()];
+        for (position, parent) in parents.iter().enumerate().rev() {
+            if let Some(parent) = *parent {
+                heights[parent] = heights[parent].max(heights[position] + 1);
+            }
+        }
+
+        let mut groups = vec![vec![]; heights[0] + 1];
+        for (node, height) in nodes.into_iter().zip(heights) {
+            groups[height].push(node);
+        }
+        groups.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::dary::Binary};
+
+    #[test]
+    fn post_order_groups_root_only() {
+        let tree: Tree<Binary, _> = Tree::with_root('a');
+        let groups: Vec<Vec<_>> = tree
+            .root()
+            .unwrap()
+            .post_order_groups()
+            .map(|group| group.iter().map(|x| *x.value()).collect())
+            .collect();
+        assert_eq!(groups, [['a']]);
+    }
+
+    #[test]
+    fn post_order_groups_multi_level() {
+        let tree = sample();
+        let groups: Vec<String> = tree
+            .root()
+            .unwrap()
+            .post_order_groups()
+            .map(|group| group.iter().map(|x| *x.value()).collect())
+            .collect();
+        assert_eq!(groups, ["deg", "bf", "c", "a"]);
+
+        let groups: Vec<String> = tree
+            .node_at(&[0])
+            .unwrap()
+            .post_order_groups()
+            .map(|group| group.iter().map(|x| *x.value()).collect())
+            .collect();
+        assert_eq!(groups, ["de", "b"]);
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{
+    traversal::breadth_first::BreadthFirst, tree_node::TreeNode,
+    variants::tree_variant::TreeVariant,
+};
+
+/// Iterator over the nodes of a subtree in the exact reverse of the depth first (pre-order) order;
+/// created by [`TreeNode::rev_walk_dfs`].
+///
+/// The reverse of a pre-order is the post-order where children are visited from right to left;
+/// therefore, nodes are yielded using a stack bounded by the depth and the branching of the subtree,
+/// without collecting the forward sequence.
+pub struct RevDepthFirst<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    stack: Vec<(bool, TreeNode<'b, 'a, V, T>)>,
+}
+
+impl<'b, 'a, V, T> Iterator for RevDepthFirst<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    type Item = TreeNode<'b, 'a, V, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.stack.pop()? {
+                (true, node) => return Some(node),
+                (false, node) => {
+                    self.stack.push((true, node));
+                    self.stack
+                        .extend(node.children().map(|child| (false, child)));
+                }
+            }
+        }
+    }
+}
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Walks the subtree rooted at this node in the reverse of the depth first (pre-order) order;
+    /// see [`RevDepthFirst`].
+    pub fn rev_walk_dfs(&self) -> RevDepthFirst<'b, 'a, V, T> {
+        RevDepthFirst {
+            stack: vec![(false, *self)],
+        }
+    }
+
+    /// Walks the subtree rooted at this node in the reverse of the breadth first order.
+    ///
+    /// Unlike [`TreeNode::rev_walk_dfs`], the forward sequence is collected first, requiring a buffer
+    /// of the size of the subtree.
+    pub fn rev_walk_bfs(&self) -> impl Iterator<Item = TreeNode<'b, 'a, V, T>> {
+        let nodes: Vec<_> = BreadthFirst::new(*self).map(|(_, _, node)| node).collect();
+        nodes.into_iter().rev()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn rev_walk_root_only() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        let root = tree.root().unwrap();
+        let values: Vec<_> = root.rev_walk_dfs().map(|x| *x.value()).collect();
+        assert_eq!(values, ['a']);
+        let values: Vec<_> = root.rev_walk_bfs().map(|x| *x.value()).collect();
+        assert_eq!(values, ['a']);
+    }
+
+    #[test]
+    fn rev_walk_multi_level() {
+        let mut tree = sample();
+        tree.push_child_at(&[1, 0], 'h');
+        tree.push_child_at(&[0, 1], 'i');
+        let root = tree.root().unwrap();
+
+        let values: String = root.rev_walk_dfs().map(|x| *x.value()).collect();
+        assert_eq!(values, "hgfciedba");
+        let values: String = root.rev_walk_bfs().map(|x| *x.value()).collect();
+        assert_eq!(values, "hgifedcba");
+
+        for node in root.walk_dfs().map(|(_, _, node)| node) {
+            let mut forward: Vec<_> = node.walk_dfs().map(|(_, _, x)| x.value()).collect();
+            forward.reverse();
+            assert_eq!(
+                node.rev_walk_dfs().map(|x| x.value()).collect::<Vec<_>>(),
+                forward
+            );
+
+            let mut forward: Vec<_> = node.walk_bfs().map(|(_, _, x)| x.value()).collect();
+            forward.reverse();
+            assert_eq!(
+                node.rev_walk_bfs().map(|x| x.value()).collect::<Vec<_>>(),
+                forward
+            );
+        }
+    }
+}
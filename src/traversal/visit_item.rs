@@ -0,0 +1,118 @@
+use crate::{
+    traversal::{breadth_first::BreadthFirst, depth_first::DepthFirst},
+    tree_node::TreeNode,
+    variants::tree_variant::TreeVariant,
+};
+
+/// Depth of a node relative to the root of a walk, which has depth zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Depth(pub usize);
+
+/// Position of a node among its siblings; the root of a walk has sibling index zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SiblingIdx(pub usize);
+
+/// Node visited by a walk together with its [`Depth`] and [`SiblingIdx`]; created by
+/// [`TreeNode::visit_dfs`] or [`TreeNode::visit_bfs`].
+pub struct VisitItem<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    depth: Depth,
+    sibling_idx: SiblingIdx,
+    node: TreeNode<'b, 'a, V, T>,
+}
+
+impl<'b, 'a, V, T> VisitItem<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    fn new((depth, sibling_idx, node): (usize, usize, TreeNode<'b, 'a, V, T>)) -> Self {
+        Self {
+            depth: Depth(depth),
+            sibling_idx: SiblingIdx(sibling_idx),
+            node,
+        }
+    }
+
+    /// Depth of the visited node.
+    pub fn depth(&self) -> Depth {
+        self.depth
+    }
+
+    /// Position of the visited node among its siblings.
+    pub fn sibling_idx(&self) -> SiblingIdx {
+        self.sibling_idx
+    }
+
+    /// The visited node.
+    pub fn node(&self) -> TreeNode<'b, 'a, V, T> {
+        self.node
+    }
+}
+
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Walks the subtree rooted at this node in depth first (pre-order) order; see [`VisitItem`].
+    pub fn visit_dfs(&self) -> impl Iterator<Item = VisitItem<'b, 'a, V, T>> {
+        DepthFirst::new(*self).map(VisitItem::new)
+    }
+
+    /// Walks the subtree rooted at this node in breadth first (level) order; see [`VisitItem`].
+    pub fn visit_bfs(&self) -> impl Iterator<Item = VisitItem<'b, 'a, V, T>> {
+        BreadthFirst::new(*self).map(VisitItem::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    #[test]
+    fn visit_root_only() {
+        let tree: Tree<AnyAry, _> = Tree::with_root('a');
+        let root = tree.root().unwrap();
+        for item in root.visit_dfs().chain(root.visit_bfs()) {
+            assert_eq!(item.depth(), Depth(0));
+            assert_eq!(item.sibling_idx(), SiblingIdx(0));
+            assert_eq!(item.node().value(), &'a');
+        }
+        assert_eq!(root.visit_dfs().count(), 1);
+        assert_eq!(root.visit_bfs().count(), 1);
+    }
+
+    #[test]
+    fn visit_multi_level() {
+        let tree = sample();
+        let root = tree.root().unwrap();
+        let items = |walk: &mut dyn Iterator<Item = VisitItem<AnyAry, char>>| -> Vec<_> {
+            walk.map(|x| (x.depth(), x.sibling_idx(), *x.node().value()))
+                .collect()
+        };
+
+        let dfs = items(&mut root.visit_dfs());
+        assert_eq!(dfs[3], (Depth(2), SiblingIdx(1), 'e'));
+        assert_eq!(dfs[4], (Depth(1), SiblingIdx(1), 'c'));
+
+        let bfs = items(&mut root.visit_bfs());
+        assert_eq!(bfs[2], (Depth(1), SiblingIdx(1), 'c'));
+        assert_eq!(bfs[4], (Depth(2), SiblingIdx(1), 'e'));
+
+        let b = tree.node_at(&[0]).unwrap();
+        let dfs = items(&mut b.visit_dfs());
+        assert_eq!(
+            dfs,
+            [
+                (Depth(0), SiblingIdx(0), 'b'),
+                (Depth(1), SiblingIdx(0), 'd'),
+                (Depth(1), SiblingIdx(1), 'e')
+            ]
+        );
+    }
+}
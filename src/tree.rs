@@ -1,4 +1,5 @@
 use crate::{
+    traversal::{breadth_first::BreadthFirst, depth_first::DepthFirst},
     tree_node::TreeNode,
     variants::tree_variant::{TreeEnds, TreeVariant},
 };
@@ -11,6 +12,7 @@ where
     V: TreeVariant<'a, T>,
 {
     pub(crate) col: SelfRefCol<'a, V, T, SplitVec<Node<'a, V, T>, Recursive>>,
+    structural_version: u64,
 }
 
 impl<'a, V, T> Tree<'a, V, T>
@@ -22,6 +24,7 @@ where
     pub fn new() -> Self {
         Self {
             col: SelfRefCol::new(),
+            structural_version: 0,
         }
     }
 
@@ -32,7 +35,7 @@ where
     }
 
     // get
-    pub fn root(&self) -> Option<TreeNode<'a, V, T>> {
+    pub fn root(&self) -> Option<TreeNode<'_, 'a, V, T>> {
         self.col.ends().root().map(TreeNode::new)
     }
 
@@ -44,6 +47,71 @@ where
         self.col.is_empty()
     }
 
+    /// Returns the structural version of the tree, which is incremented whenever nodes are added or removed.
+    ///
+    /// Mutations of the data of the nodes do not change the version; hence, caches depending only on the shape of
+    /// the tree can be invalidated by comparing versions.
+    pub fn structural_version(&self) -> u64 {
+        self.structural_version
+    }
+
+    // iter
+    /// Returns an iterator over values of all nodes of the tree in depth first (pre-order) order,
+    /// starting from the root.
+    ///
+    /// The order is determined by the tree structure only: a node is followed by the subtree of its first child,
+    /// then by that of its second child, and so on. It is independent of how the nodes are laid out in memory.
+    pub fn iter_dfs(&self) -> impl Iterator<Item = &T> + use<'_, 'a, V, T> {
+        let nodes = self.root().into_iter().flat_map(DepthFirst::new);
+        nodes.map(|(_, _, node)| node.value())
+    }
+
+    /// Returns an iterator over values of all nodes of the tree in breadth first (level) order,
+    /// starting from the root.
+    ///
+    /// The order is determined by the tree structure only: nodes at depth `d` are yielded before those at depth `d + 1`,
+    /// and nodes at the same depth are yielded in the order of their parents and then by their positions among siblings.
+    pub fn iter_bfs(&self) -> impl Iterator<Item = &T> + use<'_, 'a, V, T> {
+        let nodes = self.root().into_iter().flat_map(BreadthFirst::new);
+        nodes.map(|(_, _, node)| node.value())
+    }
+
+    /// Returns an iterator over values of all nodes of the tree in the order they are stored in memory.
+    ///
+    /// The storage order is not related to the tree structure. It follows the order in which the nodes are added
+    /// to the tree; however, removals leave holes or move nodes around when the memory is reclaimed.
+    /// [`Tree::iter_dfs`] or [`Tree::iter_bfs`] must be used whenever the order matters, such as while displaying
+    /// the tree.
+    ///
+    /// The references to the values are collected into a buffer of length [`Tree::num_nodes`] before the
+    /// iteration starts; hence, the call allocates and takes O(n) time regardless of how many values are consumed.
+    pub fn iter_storage_order(&self) -> impl Iterator<Item = &T> {
+        let values: Vec<&T> = self.col.visit_take((), |x, _| {
+            let mut values = Vec::with_capacity(x.len());
+            let mut at = 0;
+            while let Some(node) = x.get_node(at) {
+                values.extend(node.data());
+                at += 1;
+            }
+            values
+        });
+        values.into_iter()
+    }
+
+    // mut
+    /// Removes all nodes from the tree.
+    ///
+    /// Nodes are stored flat in the underlying collection rather than owned by their parents.
+    /// Therefore, clearing the tree, as well as dropping it, never recurses over the tree structure
+    /// and is safe regardless of the depth of the tree.
+    pub fn clear_iterative(&mut self) {
+        if !self.is_empty() {
+            self.col.clear();
+            self.bump_structural_version();
+            self.validate_after_mutation();
+        }
+    }
+
     // helpers
     pub(crate) fn insert_root(&mut self, root: T) {
         debug_assert!(self.is_empty());
@@ -51,13 +119,22 @@ where
             let root_node = x.push_get_ref(root);
             x.set_ends(root_node);
         });
+        self.bump_structural_version();
+        self.validate_after_mutation();
+    }
+
+    pub(crate) fn bump_structural_version(&mut self) {
+        self.structural_version = self.structural_version.wrapping_add(1);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::variants::{any_ary::AnyAry, dary::Binary};
+    use crate::{
+        test_builder::{chain, sample},
+        variants::{any_ary::AnyAry, dary::Binary},
+    };
 
     #[test]
     fn new() {
@@ -76,4 +153,82 @@ mod tests {
         assert_eq!(tree.num_nodes(), 1);
         assert_eq!(tree.root().unwrap().value(), &'a');
     }
+
+    #[test]
+    fn iter_dfs_bfs() {
+        let tree = Tree::<AnyAry, char>::new();
+        assert_eq!(tree.iter_dfs().count(), 0);
+        assert_eq!(tree.iter_bfs().count(), 0);
+
+        let tree = Tree::<AnyAry, _>::with_root('a');
+        assert_eq!(tree.iter_dfs().collect::<Vec<_>>(), [&'a']);
+        assert_eq!(tree.iter_bfs().collect::<Vec<_>>(), [&'a']);
+
+        let tree = sample();
+        assert_eq!(tree.iter_dfs().collect::<String>(), "abdecfg");
+        assert_eq!(tree.iter_bfs().collect::<String>(), "abcdefg");
+    }
+
+    #[test]
+    fn iter_storage_order() {
+        let mut tree = Tree::<AnyAry, _>::new();
+        assert_eq!(tree.iter_storage_order().count(), 0);
+
+        tree.insert_root('a');
+        tree.push_child_at(&[], 'b');
+        tree.push_child_at(&[0], 'c');
+        tree.push_child_at(&[], 'd');
+        tree.push_child_at(&[1], 'e');
+        tree.push_child_at(&[0], 'f');
+        assert_eq!(tree.iter_storage_order().collect::<String>(), "abcdef");
+        assert_eq!(tree.iter_dfs().collect::<String>(), "abcfde");
+        assert_eq!(tree.iter_bfs().collect::<String>(), "abdcfe");
+
+        tree.clear_iterative();
+        assert_eq!(tree.iter_storage_order().count(), 0);
+    }
+
+    #[test]
+    fn clear_iterative() {
+        let mut tree = Tree::<Binary, _>::with_root('a');
+
+        tree.clear_iterative();
+        assert!(tree.is_empty());
+        assert_eq!(tree.num_nodes(), 0);
+        assert!(tree.root().is_none());
+
+        tree.insert_root('b');
+        assert_eq!(tree.root().unwrap().value(), &'b');
+    }
+
+    #[test]
+    fn clear_iterative_deep() {
+        let mut tree = chain(100_000);
+        assert_eq!(tree.num_nodes(), 100_000);
+        assert_eq!(tree.iter_dfs().last(), Some(&99_999));
+
+        tree.clear_iterative();
+        assert!(tree.is_empty());
+        assert!(tree.root().is_none());
+
+        drop(chain(100_000));
+    }
+
+    #[test]
+    fn structural_version() {
+        let mut tree = Tree::<Binary, _>::new();
+        assert_eq!(tree.structural_version(), 0);
+
+        tree.insert_root('a');
+        assert_eq!(tree.structural_version(), 1);
+
+        tree.replace_root('b');
+        assert_eq!(tree.structural_version(), 1);
+
+        tree.clear_iterative();
+        assert_eq!(tree.structural_version(), 2);
+
+        tree.clear_iterative();
+        assert_eq!(tree.structural_version(), 2);
+    }
 }
@@ -1,24 +1,24 @@
 use crate::variants::tree_variant::TreeVariant;
 use orx_selfref_col::NodeRefs;
 
-pub struct TreeNode<'a, V, T>
+pub struct TreeNode<'b, 'a, V, T>
 where
     T: 'a,
     V: TreeVariant<'a, T>,
 {
-    pub(crate) node: &'a orx_selfref_col::Node<'a, V, T>,
+    pub(crate) node: &'b orx_selfref_col::Node<'a, V, T>,
 }
 
-impl<'a, V, T> TreeNode<'a, V, T>
+impl<'b, 'a, V, T> TreeNode<'b, 'a, V, T>
 where
     T: 'a,
     V: TreeVariant<'a, T>,
 {
-    pub(crate) fn new(node: &'a orx_selfref_col::Node<'a, V, T>) -> Self {
+    pub(crate) fn new(node: &'b orx_selfref_col::Node<'a, V, T>) -> Self {
         Self { node }
     }
 
-    pub fn value(&self) -> &T {
+    pub fn value(&self) -> &'b T {
         unsafe { self.node.data().unwrap_unchecked() }
     }
 
@@ -27,7 +27,19 @@ where
     }
 
     pub fn children(&self) -> impl Iterator<Item = Self> {
-        self.node.next().referenced_nodes().map(Self::new)
+        self.node
+            .next()
+            .referenced_nodes()
+            .map(|node| Self::new(node))
+    }
+
+    // helpers
+    pub(crate) fn child_slots(&self) -> impl Iterator<Item = Option<Self>> {
+        V::child_slots(self.node).map(|child| child.map(|node| Self::new(node)))
+    }
+
+    pub(crate) fn ref_eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.node, other.node)
     }
 }
 
@@ -52,4 +64,22 @@ mod tests {
         let tree: Tree<Binary, _> = Tree::with_root(42);
         assert_eq!(tree.root().unwrap().children().count(), 0);
     }
+
+    #[test]
+    fn children_skip_empty_slots() {
+        let mut tree: Tree<Binary, _> = Tree::with_root(1);
+        tree.set_child_at(&[], 1, 3);
+        tree.set_child_at(&[1], 0, 4);
+
+        let root = tree.root().unwrap();
+        let children: Vec<_> = root.children().map(|x| *x.value()).collect();
+        assert_eq!(children, [3]);
+
+        let child = root.children().next().unwrap();
+        assert_eq!(child.parent().map(|x| *x.value()), Some(1));
+        assert_eq!(
+            child.children().map(|x| *x.value()).collect::<Vec<_>>(),
+            [4]
+        );
+    }
 }
@@ -0,0 +1,154 @@
+use crate::{
+    queries::filter_view::FilteredTreeView, tree::Tree, tree_node::TreeNode,
+    variants::tree_variant::TreeVariant,
+};
+
+/// Read-only access to anything tree-shaped: a [`Tree`], the subtree rooted at a [`TreeNode`] or a
+/// [`FilteredTreeView`], allowing algorithms to be written once for all of them.
+pub trait TreeView<'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Returns the root of the view; None if the view is empty.
+    fn root(&self) -> Option<TreeNode<'_, 'a, V, T>>;
+
+    /// Returns an iterator over the children of the `node` which belong to the view.
+    fn children_of<'n>(
+        &'n self,
+        node: &TreeNode<'n, 'a, V, T>,
+    ) -> impl Iterator<Item = TreeNode<'n, 'a, V, T>>;
+
+    /// Returns whether or not the `node` belongs to the view.
+    fn contains_node(&self, node: &TreeNode<'_, 'a, V, T>) -> bool;
+
+    /// Walks the nodes of the view in depth first order; each item is a `(depth, node)` pair where the depth of
+    /// the root is zero.
+    fn walk<'n>(&'n self) -> impl Iterator<Item = (usize, TreeNode<'n, 'a, V, T>)>
+    where
+        'a: 'n,
+    {
+        let mut stack: Vec<_> = self.root().into_iter().map(|root| (0, root)).collect();
+        std::iter::from_fn(move || {
+            let (depth, node) = stack.pop()?;
+            let len = stack.len();
+            stack.extend(self.children_of(&node).map(|child| (depth + 1, child)));
+            stack[len..].reverse();
+            Some((depth, node))
+        })
+    }
+}
+
+impl<'a, V, T> TreeView<'a, V, T> for Tree<'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    fn root(&self) -> Option<TreeNode<'_, 'a, V, T>> {
+        Tree::root(self)
+    }
+
+    fn children_of<'n>(
+        &'n self,
+        node: &TreeNode<'n, 'a, V, T>,
+    ) -> impl Iterator<Item = TreeNode<'n, 'a, V, T>> {
+        node.children()
+    }
+
+    fn contains_node(&self, node: &TreeNode<'_, 'a, V, T>) -> bool {
+        Tree::root(self).is_some_and(|root| node.relative_depth_to(&root).is_some())
+    }
+}
+
+impl<'b, 'a, V, T> TreeView<'a, V, T> for TreeNode<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    fn root(&self) -> Option<TreeNode<'_, 'a, V, T>> {
+        Some(*self)
+    }
+
+    fn children_of<'n>(
+        &'n self,
+        node: &TreeNode<'n, 'a, V, T>,
+    ) -> impl Iterator<Item = TreeNode<'n, 'a, V, T>> {
+        node.children()
+    }
+
+    fn contains_node(&self, node: &TreeNode<'_, 'a, V, T>) -> bool {
+        node.relative_depth_to(self).is_some()
+    }
+}
+
+impl<'b, 'a, V, T> TreeView<'a, V, T> for FilteredTreeView<'b, 'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    fn root(&self) -> Option<TreeNode<'_, 'a, V, T>> {
+        FilteredTreeView::root(self)
+    }
+
+    fn children_of<'n>(
+        &'n self,
+        node: &TreeNode<'n, 'a, V, T>,
+    ) -> impl Iterator<Item = TreeNode<'n, 'a, V, T>> {
+        self.children(node)
+    }
+
+    fn contains_node(&self, node: &TreeNode<'_, 'a, V, T>) -> bool {
+        self.contains(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TreeView;
+    use crate::{test_builder::sample, tree::Tree, variants::any_ary::AnyAry};
+
+    fn values<'a, W: TreeView<'a, AnyAry, char>>(view: &W) -> Vec<(usize, char)> {
+        view.walk()
+            .map(|(depth, node)| (depth, *node.value()))
+            .collect()
+    }
+
+    #[test]
+    fn tree_view_empty() {
+        let tree = Tree::<AnyAry, char>::new();
+        assert!(values(&tree).is_empty());
+        assert!(values(&tree.filter_view(|_| true)).is_empty());
+    }
+
+    #[test]
+    fn tree_view_root_only() {
+        let tree = Tree::<AnyAry, _>::with_root('a');
+        let root = tree.root().unwrap();
+        assert_eq!(values(&tree), [(0, 'a')]);
+        assert_eq!(values(&root), [(0, 'a')]);
+        assert_eq!(values(&tree.filter_view(|_| true)), [(0, 'a')]);
+        assert!(tree.contains_node(&root));
+        assert!(root.contains_node(&root));
+
+        let other = Tree::<AnyAry, _>::with_root('a');
+        assert!(!tree.contains_node(&other.root().unwrap()));
+    }
+
+    #[test]
+    fn tree_view_pruned_filter() {
+        let tree = sample();
+        let expected = [(0, 'a'), (1, 'b'), (2, 'e'), (1, 'c'), (2, 'f'), (3, 'g')];
+        let view = tree.filter_view(|x| *x == 'e' || *x == 'g');
+        assert_eq!(values(&view), expected);
+        assert!(view.contains_node(&tree.node_at(&[0, 1]).unwrap()));
+        assert!(!view.contains_node(&tree.node_at(&[0, 0]).unwrap()));
+
+        let view = tree.filter_view(|x| *x == 'd');
+        assert_eq!(values(&view), [(0, 'a'), (1, 'b'), (2, 'd')]);
+
+        let c = tree.node_at(&[1]).unwrap();
+        assert_eq!(values(&c), [(0, 'c'), (1, 'f'), (2, 'g')]);
+        let view = c.filter_view(|x| *x == 'f');
+        assert_eq!(values(&view), [(0, 'c'), (1, 'f')]);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{traversal::depth_first::DepthFirst, tree::Tree, variants::tree_variant::TreeVariant};
+
+impl<'a, V, T> Tree<'a, V, T>
+where
+    T: 'a,
+    V: TreeVariant<'a, T>,
+{
+    /// Validates the structural invariants of the tree:
+    /// * the root exists if and only if the tree is not empty, and it has no parent,
+    /// * the parent of every child of a node is the node itself,
+    /// * every node of the tree is reachable from the root, and is reached exactly once.
+    ///
+    /// With the `validate` feature, this check runs automatically after each mutation in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a message describing the first violated invariant.
+    pub fn debug_validate(&self) {
+        let Some(root) = self.root() else {
+            assert!(self.is_empty(), "non-empty tree has no root");
+            return;
+        };
+        assert!(root.parent().is_none(), "root has a parent");
+
+        let mut num_reachable = 0;
+        for (depth, sibling_idx, node) in DepthFirst::new(root) {
+            num_reachable += 1;
+            assert!(
+                num_reachable <= self.num_nodes(),
+                "walk from the root reaches more nodes than the tree has; a node is linked more than once"
+            );
+            for child in node.children() {
+                let parent = child.parent();
+                assert!(
+                    parent.is_some_and(|parent| parent.ref_eq(&node)),
+                    "a child of the node at depth {} with sibling index {} does not refer back to it as its parent",
+                    depth,
+                    sibling_idx
+                );
+            }
+        }
+        assert_eq!(
+            num_reachable,
+            self.num_nodes(),
+            "number of nodes reachable from the root differs from the number of nodes"
+        );
+    }
+
+    pub(crate) fn validate_after_mutation(&self) {
+        #[cfg(all(debug_assertions, feature = "validate"))]
+        self.debug_validate();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        test_builder::sample,
+        tree::Tree,
+        variants::{any_ary::AnyAry, dary::Binary, tree_variant::TreeEnds},
+    };
+    use orx_selfref_col::NodeRefs;
+
+    #[test]
+    fn debug_validate() {
+        Tree::<AnyAry, char>::new().debug_validate();
+        Tree::<AnyAry, _>::with_root('a').debug_validate();
+        Tree::<Binary, _>::with_root('a').debug_validate();
+
+        let mut tree = Tree::<Binary, _>::with_root('a');
+        tree.clear_iterative();
+        tree.debug_validate();
+    }
+
+    #[test]
+    fn debug_validate_in_depth() {
+        sample().debug_validate();
+
+        let mut tree = Tree::<Binary, _>::with_root('a');
+        tree.set_child_at(&[], 1, 'b');
+        tree.set_child_at(&[1], 0, 'c');
+        tree.debug_validate();
+    }
+
+    #[test]
+    #[should_panic(expected = "a node is linked more than once")]
+    fn debug_validate_node_linked_twice() {
+        let mut tree = sample();
+        tree.col.move_mutate((), |x, _| {
+            let b = x.ends().root().expect("not empty").next().get()[0];
+            let d = b.next().get()[0];
+            b.next_vec_mut(&x).push(d);
+        });
+        tree.debug_validate();
+    }
+
+    #[test]
+    #[should_panic(expected = "does not refer back to it as its parent")]
+    fn debug_validate_cycle() {
+        let mut tree = sample();
+        tree.col.move_mutate((), |x, _| {
+            let a = x.ends().root().expect("not empty");
+            let g = a.next().get()[1].next().get()[0].next().get()[0];
+            g.next_vec_mut(&x).push(a);
+        });
+        tree.debug_validate();
+    }
+}
@@ -1,7 +1,11 @@
 use super::tree_variant::TreeVariant;
-use crate::{mutations::insert::Insertion, tree::Tree};
+use crate::{
+    mutations::{drain, insert::Insertion},
+    tree::Tree,
+};
 use orx_selfref_col::{
-    MemoryReclaimOnThreshold, NodeDataLazyClose, NodeRefSingle, NodeRefsVec, Variant,
+    MemoryReclaimOnThreshold, Node, NodeDataLazyClose, NodeRefSingle, NodeRefs, NodeRefsVec,
+    Variant,
 };
 
 pub struct AnyAry;
@@ -15,7 +19,7 @@ impl<'a, T: 'a> Variant<'a, T> for AnyAry {
 }
 
 impl<'a, T: 'a> TreeVariant<'a, T> for AnyAry {
-    fn insert(tree: &mut Tree<'a, Self, T>, insertion: Insertion<'a, Self, T>) {
+    fn insert(tree: &mut Tree<'a, Self, T>, insertion: Insertion<'_, 'a, Self, T>) {
         match insertion {
             Insertion::None => {}
             Insertion::AsParentOf(child) => {
@@ -27,4 +31,14 @@ impl<'a, T: 'a> TreeVariant<'a, T> for AnyAry {
         }
         todo!()
     }
+
+    fn take_values(tree: &mut Tree<'a, Self, T>) -> Vec<T> {
+        drain::take_values(&mut tree.col)
+    }
+
+    fn child_slots(
+        node: &Node<'a, Self, T>,
+    ) -> impl Iterator<Item = Option<&'a Node<'a, Self, T>>> {
+        node.next().get().iter().map(|child| Some(*child))
+    }
 }
@@ -1,7 +1,11 @@
 use super::tree_variant::TreeVariant;
-use crate::{mutations::insert::Insertion, tree::Tree};
+use crate::{
+    mutations::{drain, insert::Insertion},
+    tree::Tree,
+};
 use orx_selfref_col::{
-    MemoryReclaimOnThreshold, NodeDataLazyClose, NodeRefSingle, NodeRefsArray, Variant,
+    MemoryReclaimOnThreshold, Node, NodeDataLazyClose, NodeRefSingle, NodeRefs, NodeRefsArray,
+    Variant,
 };
 
 pub type Binary = Dary<2>;
@@ -18,7 +22,7 @@ impl<'a, const N: usize, T: 'a> Variant<'a, T> for Dary<N> {
 }
 
 impl<'a, const N: usize, T: 'a> TreeVariant<'a, T> for Dary<N> {
-    fn insert(tree: &mut Tree<'a, Self, T>, insertion: Insertion<'a, Self, T>) {
+    fn insert(tree: &mut Tree<'a, Self, T>, insertion: Insertion<'_, 'a, Self, T>) {
         match insertion {
             Insertion::None => {}
             Insertion::AsParentOf(child) => {
@@ -30,4 +34,14 @@ impl<'a, const N: usize, T: 'a> TreeVariant<'a, T> for Dary<N> {
         }
         todo!()
     }
+
+    fn take_values(tree: &mut Tree<'a, Self, T>) -> Vec<T> {
+        drain::take_values(&mut tree.col)
+    }
+
+    fn child_slots(
+        node: &Node<'a, Self, T>,
+    ) -> impl Iterator<Item = Option<&'a Node<'a, Self, T>>> {
+        node.next().get().iter().copied()
+    }
 }
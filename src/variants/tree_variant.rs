@@ -18,7 +18,15 @@ where
     T: 'a,
     Self::Ends: TreeEnds<'a, Self, T>,
 {
-    fn insert(tree: &mut Tree<'a, Self, T>, insertion: Insertion<'a, Self, T>);
+    fn insert(tree: &mut Tree<'a, Self, T>, insertion: Insertion<'_, 'a, Self, T>);
+
+    fn take_values(tree: &mut Tree<'a, Self, T>) -> Vec<T>;
+
+    /// Returns the child slots of the `node` in order; a slot is None if it does not hold a child.
+    ///
+    /// Variants with a fixed number of children expose all of their slots, while the others expose one slot per child.
+    fn child_slots(node: &Node<'a, Self, T>)
+        -> impl Iterator<Item = Option<&'a Node<'a, Self, T>>>;
 }
 
 pub trait TreeEnds<'a, V, T>